@@ -23,43 +23,310 @@
 use crate::vector::Vector;
 use core::{
     fmt::{self, Debug, Display},
+    iter::Enumerate,
     marker::PhantomData,
+    mem,
+    num::NonZeroU64,
+    slice,
 };
 
+/// A generation counter associated with arena allocation entries.
+pub trait Generation: Copy + Ord + Debug + Default {
+    /// Returns the generation following this generation, wrapping around to the default
+    /// generation on overflow.
+    fn next(self) -> Self;
+}
+
+impl Generation for u16 {
+    fn next(self) -> Self {
+        self.wrapping_add(1)
+    }
+}
+
+impl Generation for u32 {
+    fn next(self) -> Self {
+        self.wrapping_add(1)
+    }
+}
+
+impl Generation for u64 {
+    fn next(self) -> Self {
+        self.wrapping_add(1)
+    }
+}
+
+/// A generation counter augmented index to track allocation entries in an [`Arena`].
+pub trait ArenaIndex: Copy + Eq + Debug {
+    /// The generation counter type.
+    type Generation: Generation;
+
+    /// Creates a new index to the entry at the given position with the given generation.
+    ///
+    /// Returns `None` if the position cannot be represented by this index type. Every position
+    /// preceding a representable position must be representable as well.
+    fn new(idx: usize, generation: Self::Generation) -> Option<Self>;
+
+    /// Returns the position of the referenced entry in the [`Vector`] underlying an [`Arena`].
+    fn idx(&self) -> usize;
+
+    /// Returns the generation of the referenced entry.
+    fn generation(&self) -> Self::Generation;
+}
+
 /// A generational counter augemented index to track arena allocation entries.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub struct Index {
+pub struct Index<G = u64> {
     /// Generation counter.
-    pub generation: u64,
+    pub generation: G,
 
     /// Index to the [`Vector`] impl. instance underlying an [`Arena`].
     pub idx: usize,
 }
 
+impl<G> ArenaIndex for Index<G>
+where
+    G: Generation,
+{
+    type Generation = G;
+
+    fn new(idx: usize, generation: G) -> Option<Self> {
+        Some(Self { generation, idx })
+    }
+
+    fn idx(&self) -> usize {
+        self.idx
+    }
+
+    fn generation(&self) -> G {
+        self.generation
+    }
+}
+
+/// An arena index packing a 32-bit generation counter and a 32-bit position into a single
+/// `u64`.
+///
+/// The position is stored offset by one so that the packed value is never zero. This allows
+/// `Option<PackedIndex>` to be represented in the same 8 bytes as `PackedIndex`. Positions up
+/// to `u32::MAX - 1` can be represented.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PackedIndex(NonZeroU64);
+
+impl ArenaIndex for PackedIndex {
+    type Generation = u32;
+
+    fn new(idx: usize, generation: u32) -> Option<Self> {
+        let idx = u32::try_from(idx).ok()?.checked_add(1)?;
+        NonZeroU64::new((u64::from(generation) << 32) | u64::from(idx)).map(Self)
+    }
+
+    fn idx(&self) -> usize {
+        (self.0.get() as u32 - 1) as usize
+    }
+
+    fn generation(&self) -> u32 {
+        (self.0.get() >> 32) as u32
+    }
+}
+
+impl Debug for PackedIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PackedIndex")
+            .field("generation", &self.generation())
+            .field("idx", &self.idx())
+            .finish()
+    }
+}
+
+/// An [`ArenaIndex`] which may be used with an [`Arena`] allocating items of type `T`.
+///
+/// Untyped indices such as [`Index`] and [`PackedIndex`] may be used with arenas allocating
+/// any item type, while a [`TypedIndex<T>`] may only be used with arenas allocating items of
+/// type `T`. Custom index types implement this trait for the item types they support.
+pub trait IndexFor<T>: ArenaIndex {}
+
+impl<T, G> IndexFor<T> for Index<G> where G: Generation {}
+
+impl<T> IndexFor<T> for PackedIndex {}
+
+impl<T, I> IndexFor<T> for TypedIndex<T, I> where I: ArenaIndex {}
+
+/// An arena index bound to the type of the items allocated in the [`Arena`] it refers to.
+///
+/// An arena may only be created with a [`TypedIndex<T>`] if it allocates items of type `T`:
+///
+/// ```compile_fail
+/// use generational_cache::prelude::*;
+///
+/// let arena = Arena::<_, u8, TypedIndex<u16>>::with_vector(Array::<_, 2>::new()); // u8 != u16
+/// ```
+///
+/// Indices to arenas allocating different item types therefore have different types, so that
+/// an index to one arena cannot be used with another:
+///
+/// ```compile_fail
+/// use generational_cache::prelude::*;
+///
+/// let mut a = Arena::<_, u8, TypedIndex<u8>>::with_vector(Array::<_, 2>::new());
+/// let b = Arena::<_, u16, TypedIndex<u16>>::with_vector(Array::<_, 2>::new());
+///
+/// let index = a.insert(7).unwrap();
+///
+/// b.get(&index); // mismatched types
+/// ```
+///
+/// Arenas allocating the same item type share the index type, so an index to one of them may
+/// still be used with another.
+pub struct TypedIndex<T, I = Index> {
+    index: I,
+    _phantom_type: PhantomData<fn() -> T>,
+}
+
+impl<T, I> TypedIndex<T, I> {
+    /// Returns the underlying untyped index.
+    pub fn into_inner(self) -> I {
+        self.index
+    }
+}
+
+impl<T, I> ArenaIndex for TypedIndex<T, I>
+where
+    I: ArenaIndex,
+{
+    type Generation = I::Generation;
+
+    fn new(idx: usize, generation: Self::Generation) -> Option<Self> {
+        Some(Self {
+            index: I::new(idx, generation)?,
+            _phantom_type: PhantomData,
+        })
+    }
+
+    fn idx(&self) -> usize {
+        self.index.idx()
+    }
+
+    fn generation(&self) -> Self::Generation {
+        self.index.generation()
+    }
+}
+
+impl<T, I> Clone for TypedIndex<T, I>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            index: self.index.clone(),
+            _phantom_type: PhantomData,
+        }
+    }
+}
+
+impl<T, I> Copy for TypedIndex<T, I> where I: Copy {}
+
+impl<T, I> PartialEq for TypedIndex<T, I>
+where
+    I: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T, I> Eq for TypedIndex<T, I> where I: Eq {}
+
+impl<T, I> Debug for TypedIndex<T, I>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedIndex").field(&self.index).finish()
+    }
+}
+
 /// An allocation entry in a generational arena.
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub enum Entry<T> {
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Entry<T, G = u64> {
     /// An occupied entry containing an allocated value and the associated generation counter.
-    Occupied { value: T, generation: u64 },
+    Occupied { value: T, generation: G },
 
-    /// Free entry pointing to next free entry in the free list.
-    Free { next_free_idx: Option<usize> },
+    /// Free entry pointing to next free entry in the free list, along with the generation
+    /// counter to be associated with the next value allocated in it.
+    Free {
+        next_free_idx: Option<usize>,
+        generation: G,
+    },
 
     /// An unmapped arena entry.
+    #[default]
     Unmapped,
 }
 
-impl<T> Default for Entry<T> {
-    fn default() -> Self {
-        Self::Unmapped
+impl<T, G> Entry<T, G>
+where
+    G: Generation,
+{
+    /// Returns the generation counter to be associated with the next value allocated in this
+    /// entry, or `None` if this entry is unmapped.
+    fn next_generation(&self) -> Option<G> {
+        match self {
+            Entry::Occupied { generation, .. } => Some(generation.next()),
+            Entry::Free { generation, .. } => Some(*generation),
+            Entry::Unmapped => None,
+        }
     }
 }
 
+/// Strategy used by an [`Arena`] for choosing the free entry to allocate the next item in.
+///
+/// The free entries of an [`Arena`] are maintained in a singly linked free list, from the head
+/// of which new items are allocated. Strategies differ in where entries are linked in the free
+/// list when items are removed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReuseStrategy {
+    /// Reuses the most recently freed entry first.
+    ///
+    /// Inserts and removals are `O(1)`. Recently freed entries, along with their generation
+    /// counters, are reused the soonest.
+    #[default]
+    Lifo,
+
+    /// Reuses the least recently freed entry first.
+    ///
+    /// Inserts and removals are `O(1)`. Reuse of an entry is delayed for as long as possible,
+    /// which reduces the chances of stale indices matching newer allocations after the
+    /// generation counter of the entry wraps around.
+    Fifo,
+
+    /// Reuses the free entry with the lowest position in the underlying [`Vector`] first.
+    ///
+    /// Inserts are `O(1)`, while removals are `O(f)` in the worst case, where `f` is the number
+    /// of free entries, since the free list is kept sorted. Removals of entries beyond every
+    /// other free entry are `O(1)`. Allocated items are kept dense towards the front of the
+    /// underlying [`Vector`], for better cache locality.
+    LowestIndex,
+}
+
 /// A generational arena for allocating memory based off a vector. Every
 /// entry is associated with a generation counter to uniquely identify
 /// newer allocations from older reclaimed allocations at the same
 /// position in the vector.
 ///
+/// Generation counters are maintained per entry. The generation of an entry is incremented
+/// every time the item allocated in it is removed, and is preserved across [`Arena::clear`]
+/// and [`Arena::shrink_to`]. As a result, an [`Index`] never matches a newer allocation at the
+/// same position, unless the generation counter of the entry wraps around, at which point it
+/// restarts from `0`. With the default [`Index`], this happens after `2^64` removals from the
+/// same entry.
+///
+/// The index type is configurable through the [`ArenaIndex`] trait. [`Index`] may be used with
+/// narrower [`Generation`] counters, while [`PackedIndex`] stores a 32-bit generation and
+/// position in a single `u64`. [`TypedIndex`] binds either to the type of the allocated items,
+/// as enforced by [`IndexFor`].
+///
+/// The free entry in which the next item is allocated is chosen as per the [`ReuseStrategy`]
+/// of the arena, which defaults to [`ReuseStrategy::Lifo`].
+///
 /// This is inspired from the crate
 /// ["generational-arena"](https://docs.rs/generational-arena)
 ///
@@ -83,15 +350,20 @@ impl<T> Default for Entry<T> {
 ///
 /// assert!(arena.get(&index).is_none());
 /// ```
-pub struct Arena<V, T> {
+pub struct Arena<V, T, I = Index>
+where
+    I: ArenaIndex,
+{
     entries_vec: V,
-    generation: u64,
+    unmapped_generation: I::Generation,
     free_list_head: Option<usize>,
+    free_list_tail: Option<usize>,
+    reuse_strategy: ReuseStrategy,
 
     len: usize,
     capacity: usize,
 
-    _phantom_type: PhantomData<T>,
+    _phantom_type: PhantomData<(T, I)>,
 }
 
 /// Error type associated with arena operations.
@@ -103,6 +375,9 @@ pub enum ArenaError<VE> {
     /// Used when referencing items in an [`Arena`] with an invalid [`Index`].
     InvalidIdx,
 
+    /// Used when requesting multiple mutable references to the same item in an [`Arena`].
+    AliasedIdx,
+
     /// Used when there is an error in the underlying [`Vector`]
     /// implemenation instance.
     VectorError(VE),
@@ -117,61 +392,350 @@ where
     }
 }
 
+/// Inconsistency in the internal state of an [`Arena`], reported by [`Arena::validate`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ArenaViolation {
+    /// Used when the number of entries in the underlying [`Vector`] differs from the capacity.
+    CapacityMismatch { capacity: usize, entries: usize },
+
+    /// Used when the entry at the given position is unmapped, despite being within the
+    /// capacity.
+    UnmappedEntry { idx: usize },
+
+    /// Used when the number of occupied entries differs from the length.
+    LenMismatch { len: usize, occupied: usize },
+
+    /// Used when the free list links to the entry at the given position, which is either
+    /// occupied or out of bounds.
+    FreeListBroken { idx: usize },
+
+    /// Used when the free list revisits the entry at the given position.
+    FreeListCycle { idx: usize },
+
+    /// Used when only some of the free entries are reachable from the head of the free list.
+    FreeListUnreachable { reachable: usize, free: usize },
+
+    /// Used when the tail of the free list is not the last entry reachable from its head.
+    FreeListTailMismatch {
+        tail: Option<usize>,
+        last: Option<usize>,
+    },
+
+    /// Used when the free list is not sorted with [`ReuseStrategy::LowestIndex`], as the entry
+    /// at position `idx` is followed by the entry at position `next_idx`.
+    FreeListUnsorted { idx: usize, next_idx: usize },
+}
+
+impl Display for ArenaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Type alias for the entries of an [`Arena`] using the given [`ArenaIndex`] type.
+pub type ArenaEntry<T, I = Index> = Entry<T, <I as ArenaIndex>::Generation>;
+
 /// A generational arena.
-impl<V, T> Arena<V, T>
+impl<V, T, I> Arena<V, T, I>
 where
-    V: Vector<Entry<T>>,
+    V: Vector<ArenaEntry<T, I>>,
+    I: ArenaIndex,
 {
     /// Reserves space for the given number of additional items in this arena.
+    ///
+    /// This arena is left unchanged if the underlying [`Vector`] is unable to accommodate the
+    /// additional items.
     pub fn reserve(&mut self, additional: usize) -> Result<(), ArenaError<V::Error>> {
+        if additional == 0 {
+            return Ok(());
+        }
+
         let reserve_start = self.entries_vec.len();
-        let old_head = self.free_list_head;
+        let reserve_end = reserve_start
+            .checked_add(additional - 1)
+            .ok_or(ArenaError::OutOfMemory)?;
 
         self.entries_vec
             .reserve(additional)
             .map_err(ArenaError::VectorError)?;
 
+        if self.entries_vec.capacity() - reserve_start < additional {
+            return Err(ArenaError::OutOfMemory);
+        }
+
         for i in 0..additional {
             let free_idx = i + reserve_start;
 
-            let next_free_idx = if i < additional - 1 {
-                Some(free_idx + 1)
-            } else {
-                old_head
-            };
+            let next_free_idx = Some(free_idx + 1).filter(|_| i < additional - 1);
 
-            let free_entry = Entry::Free { next_free_idx };
-            self.entries_vec
-                .push(free_entry)
-                .map_err(ArenaError::VectorError)?;
+            let free_entry = Entry::Free {
+                next_free_idx,
+                generation: self.unmapped_generation,
+            };
+            if let Err(error) = self.entries_vec.push(free_entry) {
+                self.entries_vec.truncate(reserve_start);
+                return Err(ArenaError::VectorError(error));
+            }
         }
 
-        self.free_list_head = Some(reserve_start);
+        // Reserved entries are positioned beyond every other free entry, and hence are linked
+        // after them, unless the most recently freed entries are to be reused first.
+        match (self.reuse_strategy, self.free_list_tail) {
+            (ReuseStrategy::Lifo, _) | (_, None) => {
+                self.set_free_list_next(reserve_end, self.free_list_head);
+                self.free_list_head = Some(reserve_start);
+                self.free_list_tail = self.free_list_tail.or(Some(reserve_end));
+            }
+            (_, Some(tail_idx)) => {
+                self.set_free_list_next(tail_idx, Some(reserve_start));
+                self.free_list_tail = Some(reserve_end);
+            }
+        }
 
         self.capacity += additional;
 
         Ok(())
     }
 
+    /// Shrinks this arena to the given capacity, releasing the excess entries in the underlying
+    /// [`Vector`].
+    ///
+    /// Occupied entries beyond the new capacity are moved into free entries within it. After
+    /// every move, `relocated` is invoked with this arena, and the old and new [`Index`] of the
+    /// moved item, so that references to the item can be updated. The callback must not insert
+    /// or remove items.
+    ///
+    /// The capacity of this arena is never reduced below its length.
+    pub fn shrink_to<F>(&mut self, new_capacity: usize, mut relocated: F)
+    where
+        F: FnMut(&mut Self, I, I),
+    {
+        let new_capacity = new_capacity.max(self.len);
+
+        if new_capacity >= self.capacity {
+            return;
+        }
+
+        self.rebuild_free_list(new_capacity);
+
+        for idx in new_capacity..self.entries_vec.len() {
+            if !matches!(self.entries_vec.get(idx), Some(Entry::Occupied { .. })) {
+                continue;
+            }
+
+            let new_idx = match self.free_list_head {
+                Some(new_idx) if new_idx < new_capacity => new_idx,
+                _ => break,
+            };
+
+            let (next_free_idx, new_generation) = match self.entries_vec.get(new_idx) {
+                Some(&Entry::Free {
+                    next_free_idx,
+                    generation,
+                }) => (next_free_idx, generation),
+                _ => break,
+            };
+
+            let (old_index, new_index) = match self.entries_vec.get(idx) {
+                Some(&Entry::Occupied { generation, .. }) => {
+                    match (I::new(idx, generation), I::new(new_idx, new_generation)) {
+                        (Some(old_index), Some(new_index)) => (old_index, new_index),
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+
+            let value = match self.entries_vec.get_mut(idx).map(mem::take) {
+                Some(Entry::Occupied { value, generation }) => {
+                    if let Some(entry) = self.entries_vec.get_mut(idx) {
+                        *entry = Entry::Free {
+                            next_free_idx: None,
+                            generation: generation.next(),
+                        };
+                    }
+
+                    value
+                }
+                _ => continue,
+            };
+
+            self.free_list_head = next_free_idx;
+            self.free_list_tail = self.free_list_tail.filter(|_| next_free_idx.is_some());
+
+            if let Some(entry) = self.entries_vec.get_mut(new_idx) {
+                *entry = Entry::Occupied {
+                    value,
+                    generation: new_generation,
+                };
+            }
+
+            relocated(self, old_index, new_index);
+        }
+
+        // Entries which could not be moved are retained along with the entries preceding them.
+        let occupied_end = self
+            .entries_vec
+            .iter()
+            .rposition(|entry| matches!(entry, Entry::Occupied { .. }))
+            .map_or(0, |idx| idx + 1);
+
+        let new_capacity = new_capacity.max(occupied_end);
+
+        // Entries mapped again later must not reuse the generations of the released entries.
+        for entry in self.entries_vec.iter().skip(new_capacity) {
+            if let Some(generation) = entry.next_generation() {
+                self.unmapped_generation = self.unmapped_generation.max(generation);
+            }
+        }
+
+        self.entries_vec.truncate(new_capacity);
+        self.capacity = new_capacity;
+
+        self.rebuild_free_list(new_capacity);
+    }
+
+    /// Moves all allocated items to the front of the underlying [`Vector`] and releases the
+    /// memory for the free entries following them. The capacity of this arena is reduced to
+    /// its length.
+    ///
+    /// After every move, `remap` is invoked with the old and new [`Index`] of the moved item.
+    pub fn compact<F>(&mut self, mut remap: F)
+    where
+        F: FnMut(I, I),
+    {
+        self.shrink_to(0, |_, old_index, new_index| remap(old_index, new_index));
+    }
+
+    /// Relinks the free entries preceding the given limit into a free list in ascending order.
+    /// Free entries at or beyond the limit are left out of the free list.
+    fn rebuild_free_list(&mut self, limit: usize) {
+        let mut free_list_head = None;
+        let mut free_list_tail = None;
+
+        for idx in (0..limit).rev() {
+            if let Some(Entry::Free { next_free_idx, .. }) = self.entries_vec.get_mut(idx) {
+                *next_free_idx = free_list_head;
+                free_list_head = Some(idx);
+                free_list_tail = free_list_tail.or(Some(idx));
+            }
+        }
+
+        self.free_list_head = free_list_head;
+        self.free_list_tail = free_list_tail;
+    }
+
+    /// Links the free entry at the given position into the free list, as per the
+    /// [`ReuseStrategy`] of this arena.
+    fn link_free_entry(&mut self, idx: usize) {
+        let prev_idx = match self.reuse_strategy {
+            ReuseStrategy::Lifo => None,
+            ReuseStrategy::Fifo => self.free_list_tail,
+            ReuseStrategy::LowestIndex => self.free_list_predecessor(idx),
+        };
+
+        let next_idx = match prev_idx {
+            Some(prev_idx) => self.free_list_next(prev_idx),
+            None => self.free_list_head,
+        };
+
+        self.set_free_list_next(idx, next_idx);
+
+        match prev_idx {
+            Some(prev_idx) => self.set_free_list_next(prev_idx, Some(idx)),
+            None => self.free_list_head = Some(idx),
+        }
+
+        if next_idx.is_none() {
+            self.free_list_tail = Some(idx);
+        }
+    }
+
+    /// Returns the position of the last free entry in the free list preceding the given
+    /// position, assuming that the free list is sorted.
+    fn free_list_predecessor(&self, idx: usize) -> Option<usize> {
+        if let Some(tail_idx) = self.free_list_tail.filter(|&tail_idx| tail_idx < idx) {
+            return Some(tail_idx);
+        }
+
+        let mut prev_idx = None;
+        let mut cursor = self.free_list_head;
+
+        while let Some(free_idx) = cursor.filter(|&free_idx| free_idx < idx) {
+            prev_idx = Some(free_idx);
+            cursor = self.free_list_next(free_idx);
+        }
+
+        prev_idx
+    }
+
+    /// Returns the position of the free entry following the free entry at the given position
+    /// in the free list.
+    fn free_list_next(&self, idx: usize) -> Option<usize> {
+        match self.entries_vec.get(idx) {
+            Some(&Entry::Free { next_free_idx, .. }) => next_free_idx,
+            _ => None,
+        }
+    }
+
+    /// Sets the position of the free entry following the free entry at the given position in
+    /// the free list.
+    fn set_free_list_next(&mut self, idx: usize, next_idx: Option<usize>) {
+        if let Some(Entry::Free { next_free_idx, .. }) = self.entries_vec.get_mut(idx) {
+            *next_free_idx = next_idx;
+        }
+    }
+
+    /// Returns the [`ReuseStrategy`] used by this arena.
+    pub fn reuse_strategy(&self) -> ReuseStrategy {
+        self.reuse_strategy
+    }
+
+    /// Sets the [`ReuseStrategy`] used by this arena for subsequent allocations.
+    ///
+    /// Switching to [`ReuseStrategy::LowestIndex`] sorts the free list, which is `O(n)` in the
+    /// capacity of this arena. Switching to any other strategy is `O(1)`.
+    pub fn set_reuse_strategy(&mut self, reuse_strategy: ReuseStrategy) {
+        if reuse_strategy == ReuseStrategy::LowestIndex
+            && self.reuse_strategy != ReuseStrategy::LowestIndex
+        {
+            self.rebuild_free_list(self.capacity);
+        }
+
+        self.reuse_strategy = reuse_strategy;
+    }
+
     /// Removes all items from this arena and reclaims all allocated memory.
+    ///
+    /// The generation counters of the entries are preserved, so that indices to the removed
+    /// items do not match newer allocations.
     pub fn clear(&mut self) -> Result<(), ArenaError<V::Error>> {
-        self.free_list_head = Some(0);
-        self.generation = 0;
-        self.len = 0;
+        let capacity = self.capacity();
 
-        self.entries_vec.clear();
+        self.entries_vec.truncate(capacity);
 
-        let capacity = self.capacity();
+        let mapped_len = self.entries_vec.len();
 
-        for i in 0..capacity {
-            let next_free_idx = i + 1;
-            let next_free_idx = if next_free_idx < capacity {
-                Some(next_free_idx)
-            } else {
-                None
+        let next_free_idx = |idx: usize| Some(idx + 1).filter(|&next| next < capacity);
+
+        for (idx, entry) in self.entries_vec.iter_mut().enumerate() {
+            let generation = entry.next_generation().unwrap_or(self.unmapped_generation);
+
+            *entry = Entry::Free {
+                next_free_idx: next_free_idx(idx),
+                generation,
             };
+        }
+
+        self.free_list_head = Some(0).filter(|_| capacity > 0);
+        self.free_list_tail = capacity.checked_sub(1);
+        self.len = 0;
 
-            let free_entry = Entry::Free { next_free_idx };
+        for idx in mapped_len..capacity {
+            let free_entry = Entry::Free {
+                next_free_idx: next_free_idx(idx),
+                generation: self.unmapped_generation,
+            };
             self.entries_vec
                 .push(free_entry)
                 .map_err(ArenaError::VectorError)?;
@@ -182,73 +746,111 @@ where
 
     /// Creates an [`Arena`] instance with the given [`Vector`]
     /// implemenation instance as the backing memory.
-    pub fn with_vector(vector: V) -> Self {
+    ///
+    /// # Panics
+    ///
+    /// Panics if the free entries cannot be pushed into the given [`Vector`]. Use
+    /// [`Arena::try_with_vector`] for a non-panicking alternative.
+    pub fn with_vector(vector: V) -> Self
+    where
+        I: IndexFor<T>,
+    {
+        Self::try_with_vector(vector).unwrap()
+    }
+
+    /// Creates an [`Arena`] instance with the given [`Vector`]
+    /// implemenation instance as the backing memory.
+    ///
+    /// Returns an error if the free entries cannot be pushed into the given [`Vector`].
+    pub fn try_with_vector(mut vector: V) -> Result<Self, ArenaError<V::Error>>
+    where
+        I: IndexFor<T>,
+    {
         let capacity = vector.capacity();
 
+        vector.clear();
+
         let mut arena = Self {
             entries_vec: vector,
-            generation: 0,
+            unmapped_generation: Default::default(),
             free_list_head: Some(0),
+            free_list_tail: None,
+            reuse_strategy: ReuseStrategy::default(),
             len: 0,
             capacity,
             _phantom_type: PhantomData,
         };
 
-        arena.clear().unwrap();
+        arena.clear()?;
 
-        arena
+        Ok(arena)
     }
 
     /// Allocates space for the given items and inserts it into this arena.
-    pub fn insert(&mut self, item: T) -> Result<Index, ArenaError<V::Error>> {
-        let old_free = self.free_list_head.ok_or(ArenaError::OutOfMemory)?;
+    ///
+    /// Returns [`ArenaError::OutOfMemory`] if no free entry is available, or if the position
+    /// of the next free entry cannot be represented by the [`ArenaIndex`] type.
+    pub fn insert(&mut self, item: T) -> Result<I, ArenaError<V::Error>> {
+        self.insert_with(|_| item)
+    }
 
-        self.free_list_head = self
-            .entries_vec
-            .get(old_free)
-            .map(|x| match x {
-                Entry::Free { next_free_idx } => *next_free_idx,
-                _ => None,
-            })
-            .ok_or(ArenaError::InvalidIdx)?;
+    /// Allocates space for an item and inserts the item created by the given function into
+    /// this arena. The function is invoked with the [`ArenaIndex`] the item will occupy.
+    ///
+    /// Returns [`ArenaError::OutOfMemory`] if no free entry is available, or if the position
+    /// of the next free entry cannot be represented by the [`ArenaIndex`] type. The function is
+    /// not invoked on errors.
+    pub fn insert_with<F>(&mut self, create: F) -> Result<I, ArenaError<V::Error>>
+    where
+        F: FnOnce(I) -> T,
+    {
+        let vacant_entry = self.vacant_entry()?;
+        let index = vacant_entry.index();
 
-        let entry = Entry::Occupied {
-            value: item,
-            generation: self.generation,
-        };
+        Ok(vacant_entry.insert(create(index)))
+    }
 
-        *self
-            .entries_vec
-            .get_mut(old_free)
-            .ok_or(ArenaError::InvalidIdx)? = entry;
-        self.generation += 1;
+    /// Returns a handle to the free entry the next item inserted into this arena will occupy.
+    ///
+    /// Returns [`ArenaError::OutOfMemory`] if no free entry is available, or if the position
+    /// of the next free entry cannot be represented by the [`ArenaIndex`] type.
+    pub fn vacant_entry(&mut self) -> Result<VacantEntry<'_, V, T, I>, ArenaError<V::Error>> {
+        let free_idx = self.free_list_head.ok_or(ArenaError::OutOfMemory)?;
+
+        let (next_free_idx, generation) = match self.entries_vec.get(free_idx) {
+            Some(&Entry::Free {
+                next_free_idx,
+                generation,
+            }) => Ok((next_free_idx, generation)),
+            _ => Err(ArenaError::InvalidIdx),
+        }?;
 
-        self.len += 1;
+        let index = I::new(free_idx, generation).ok_or(ArenaError::OutOfMemory)?;
 
-        Ok(Index {
-            generation: self.generation - 1,
-            idx: old_free,
+        Ok(VacantEntry {
+            arena: self,
+            index,
+            next_free_idx,
         })
     }
 
     /// Reclaims the allocated space for the item at the given index and
     /// removes it from the arena.
-    pub fn remove(&mut self, index: &Index) -> Option<T> {
-        match self.entries_vec.get(index.idx) {
+    pub fn remove(&mut self, index: &I) -> Option<T> {
+        match self.entries_vec.get(index.idx()) {
             Some(Entry::Occupied {
                 value: _,
                 generation,
-            }) if &index.generation == generation => {
-                let new_free_list_head_entry = Entry::<T>::Free {
-                    next_free_idx: self.free_list_head,
+            }) if &index.generation() == generation => {
+                let new_free_entry = Entry::Free {
+                    next_free_idx: None,
+                    generation: generation.next(),
                 };
 
-                let old_entry = core::mem::replace(
-                    self.entries_vec.get_mut(index.idx)?,
-                    new_free_list_head_entry,
-                );
+                let old_entry =
+                    mem::replace(self.entries_vec.get_mut(index.idx())?, new_free_entry);
 
-                self.free_list_head = Some(index.idx);
+                self.link_free_entry(index.idx());
 
                 self.len -= 1;
 
@@ -265,26 +867,101 @@ where
         })
     }
 
-    /// Returns a mutable reference to the allocated items referenced by the given [`Index`].
-    pub fn get_mut(&mut self, index: &Index) -> Option<&mut T> {
-        match self.entries_vec.get_mut(index.idx) {
-            Some(Entry::Occupied { value, generation }) if &index.generation == generation => {
-                Some(value)
+    /// Replaces the item at the given index with the given item in place, returning the new
+    /// index to the entry along with the replaced item.
+    ///
+    /// The generation of the entry is advanced as if the item was removed and the given item
+    /// inserted into the freed entry, so that the given index becomes stale. Returns `None`,
+    /// leaving this arena unchanged, if the given index is invalid.
+    pub fn replace(&mut self, index: &I, item: T) -> Option<(I, T)> {
+        let entry = self.entries_vec.get_mut(index.idx())?;
+
+        let generation = match entry {
+            Entry::Occupied { generation, .. } if &index.generation() == generation => {
+                generation.next()
             }
+            _ => return None,
+        };
+
+        let new_index = I::new(index.idx(), generation)?;
+
+        match mem::replace(
+            entry,
+            Entry::Occupied {
+                value: item,
+                generation,
+            },
+        ) {
+            Entry::Occupied { value, .. } => Some((new_index, value)),
             _ => None,
         }
     }
 
-    /// Returns an immutable reference to the allocated items referenced by the given [`Index`].
-    pub fn get(&self, index: &Index) -> Option<&T> {
-        match self.entries_vec.get(index.idx) {
-            Some(Entry::Occupied { value, generation }) if &index.generation == generation => {
+    /// Returns a mutable reference to the allocated items referenced by the given [`Index`].
+    pub fn get_mut(&mut self, index: &I) -> Option<&mut T> {
+        match self.entries_vec.get_mut(index.idx()) {
+            Some(Entry::Occupied { value, generation }) if &index.generation() == generation => {
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns an immutable reference to the allocated items referenced by the given [`Index`].
+    pub fn get(&self, index: &I) -> Option<&T> {
+        match self.entries_vec.get(index.idx()) {
+            Some(Entry::Occupied { value, generation }) if &index.generation() == generation => {
                 Some(value)
             }
             _ => None,
         }
     }
 
+    /// Returns mutable references to the distinct allocated items referenced by the given
+    /// indices.
+    ///
+    /// Returns [`ArenaError::InvalidIdx`] if an index does not reference an allocated item, or
+    /// [`ArenaError::AliasedIdx`] if both indices reference the same item.
+    #[allow(clippy::type_complexity)]
+    pub fn get2_mut(
+        &mut self,
+        index_a: &I,
+        index_b: &I,
+    ) -> Result<(&mut T, &mut T), ArenaError<V::Error>> {
+        let [item_a, item_b] = self.get_many_mut(&[*index_a, *index_b])?;
+        Ok((item_a, item_b))
+    }
+
+    /// Returns mutable references to the distinct allocated items referenced by the given
+    /// indices, in the same order as the indices.
+    ///
+    /// Returns [`ArenaError::InvalidIdx`] if an index does not reference an allocated item, or
+    /// [`ArenaError::AliasedIdx`] if multiple indices reference the same item.
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        indices: &[I; N],
+    ) -> Result<[&mut T; N], ArenaError<V::Error>> {
+        for (i, index) in indices.iter().enumerate() {
+            if self.get(index).is_none() {
+                return Err(ArenaError::InvalidIdx);
+            }
+
+            if indices[..i].iter().any(|other| other.idx() == index.idx()) {
+                return Err(ArenaError::AliasedIdx);
+            }
+        }
+
+        let entries = self
+            .entries_vec
+            .get_disjoint_mut(indices.map(|index| index.idx()))
+            .map_err(|_| ArenaError::AliasedIdx)?;
+
+        Ok(entries.map(|entry| match entry {
+            Entry::Occupied { value, .. } => value,
+            _ => unreachable!("Entries validated to be occupied."),
+        }))
+    }
+
     /// Returns the number of elements this [`Arena`] is capable of allocating.
     pub fn capacity(&self) -> usize {
         self.capacity
@@ -299,12 +976,420 @@ where
     pub fn len(&self) -> usize {
         self.len
     }
+
+    /// Returns an iterator over the indices of and references to the items allocated in this
+    /// arena, in the order of their positions in the underlying [`Vector`].
+    pub fn iter(&self) -> Iter<'_, T, I> {
+        Iter {
+            entries: self.entries_vec.iter().enumerate(),
+            remaining: self.len,
+            _phantom_type: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the indices of and mutable references to the items allocated in
+    /// this arena, in the order of their positions in the underlying [`Vector`].
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, I> {
+        IterMut {
+            entries: self.entries_vec.iter_mut().enumerate(),
+            remaining: self.len,
+            _phantom_type: PhantomData,
+        }
+    }
+
+    /// Returns an iterator which removes the items allocated in this arena and yields them
+    /// along with their indices, in the order of their positions in the underlying [`Vector`].
+    ///
+    /// All remaining items are removed when the iterator is dropped.
+    pub fn drain(&mut self) -> Drain<'_, V, T, I> {
+        Drain {
+            arena: self,
+            cursor: 0,
+        }
+    }
+
+    /// Retains only the items for which the given predicate returns `true`, removing the rest.
+    ///
+    /// The predicate is invoked with the index of and a mutable reference to every item, in
+    /// the order of their positions in the underlying [`Vector`].
+    pub fn retain<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(I, &mut T) -> bool,
+    {
+        for idx in 0..self.entries_vec.len() {
+            let index = match self.entries_vec.get_mut(idx) {
+                Some(Entry::Occupied { value, generation }) => match I::new(idx, *generation) {
+                    Some(index) if !pred(index, value) => index,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+
+            self.remove(&index);
+        }
+    }
+
+    /// Checks the internal consistency of this arena.
+    ///
+    /// Verifies that the length matches the number of occupied entries, and that every free
+    /// entry is reachable exactly once from the head of the free list, in the order required
+    /// by the [`ReuseStrategy`] of this arena. Returns the first [`ArenaViolation`] found.
+    ///
+    /// This operation is `O(n)` in the capacity of this arena.
+    pub fn validate(&self) -> Result<(), ArenaViolation> {
+        let entries = self.entries_vec.len();
+
+        if entries != self.capacity {
+            return Err(ArenaViolation::CapacityMismatch {
+                capacity: self.capacity,
+                entries,
+            });
+        }
+
+        let (mut occupied, mut free) = (0, 0);
+
+        for (idx, entry) in self.entries_vec.iter().enumerate() {
+            match entry {
+                Entry::Occupied { .. } => occupied += 1,
+                Entry::Free { .. } => free += 1,
+                Entry::Unmapped => return Err(ArenaViolation::UnmappedEntry { idx }),
+            }
+        }
+
+        if occupied != self.len {
+            return Err(ArenaViolation::LenMismatch {
+                len: self.len,
+                occupied,
+            });
+        }
+
+        let mut reachable = 0;
+        let mut last = None;
+        let mut cursor = self.free_list_head;
+
+        while let Some(idx) = cursor {
+            let next_free_idx = match self.entries_vec.get(idx) {
+                Some(&Entry::Free { next_free_idx, .. }) => next_free_idx,
+                _ => return Err(ArenaViolation::FreeListBroken { idx }),
+            };
+
+            // Visiting more entries than there are free entries implies revisiting one.
+            if reachable == free {
+                return Err(ArenaViolation::FreeListCycle { idx });
+            }
+
+            if let (ReuseStrategy::LowestIndex, Some(next_idx)) =
+                (self.reuse_strategy, next_free_idx)
+            {
+                if next_idx <= idx {
+                    return Err(ArenaViolation::FreeListUnsorted { idx, next_idx });
+                }
+            }
+
+            reachable += 1;
+            last = Some(idx);
+            cursor = next_free_idx;
+        }
+
+        if reachable != free {
+            return Err(ArenaViolation::FreeListUnreachable { reachable, free });
+        }
+
+        if self.free_list_tail != last {
+            return Err(ArenaViolation::FreeListTailMismatch {
+                tail: self.free_list_tail,
+                last,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the index of the first occupied entry at or after the given position.
+    fn next_occupied(&self, from: usize) -> Option<I> {
+        self.entries_vec
+            .iter()
+            .enumerate()
+            .skip(from)
+            .find_map(|(idx, entry)| match entry {
+                Entry::Occupied { generation, .. } => I::new(idx, *generation),
+                _ => None,
+            })
+    }
+}
+
+/// Dumps the internal state of an [`Arena`], including every entry and the free list.
+impl<V, T, I> Debug for Arena<V, T, I>
+where
+    V: Vector<ArenaEntry<T, I>>,
+    T: Debug,
+    I: ArenaIndex,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Arena")
+            .field("len", &self.len)
+            .field("capacity", &self.capacity)
+            .field("reuse_strategy", &self.reuse_strategy)
+            .field("free_list_head", &self.free_list_head)
+            .field("free_list_tail", &self.free_list_tail)
+            .field("unmapped_generation", &self.unmapped_generation)
+            .field("entries", &&self.entries_vec[..])
+            .finish()
+    }
+}
+
+/// A free entry in an [`Arena`], obtained with [`Arena::vacant_entry`].
+///
+/// Exposes the [`ArenaIndex`] an item will occupy before it is inserted. Dropping a vacant
+/// entry leaves the arena unchanged.
+pub struct VacantEntry<'a, V, T, I = Index>
+where
+    V: Vector<ArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    arena: &'a mut Arena<V, T, I>,
+    index: I,
+    next_free_idx: Option<usize>,
+}
+
+impl<V, T, I> VacantEntry<'_, V, T, I>
+where
+    V: Vector<ArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    /// Returns the [`ArenaIndex`] the item inserted into this entry will occupy.
+    pub fn index(&self) -> I {
+        self.index
+    }
+
+    /// Inserts the given item into this entry and returns its [`ArenaIndex`].
+    pub fn insert(self, item: T) -> I {
+        let Self {
+            arena,
+            index,
+            next_free_idx,
+        } = self;
+
+        if let Some(entry) = arena.entries_vec.get_mut(index.idx()) {
+            *entry = Entry::Occupied {
+                value: item,
+                generation: index.generation(),
+            };
+
+            arena.free_list_head = next_free_idx;
+            arena.free_list_tail = arena.free_list_tail.filter(|_| next_free_idx.is_some());
+            arena.len += 1;
+        }
+
+        index
+    }
+}
+
+/// Iterator over the items allocated in an [`Arena`].
+pub struct Iter<'a, T, I = Index>
+where
+    I: ArenaIndex,
+{
+    entries: Enumerate<slice::Iter<'a, ArenaEntry<T, I>>>,
+    remaining: usize,
+    _phantom_type: PhantomData<I>,
+}
+
+impl<'a, T, I> Iterator for Iter<'a, T, I>
+where
+    I: ArenaIndex,
+{
+    type Item = (I, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.entries.find_map(|(idx, entry)| match entry {
+            Entry::Occupied { value, generation } => Some((I::new(idx, *generation)?, value)),
+            _ => None,
+        })?;
+
+        self.remaining -= 1;
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, I> ExactSizeIterator for Iter<'_, T, I> where I: ArenaIndex {}
+
+/// Iterator over mutable references to the items allocated in an [`Arena`].
+pub struct IterMut<'a, T, I = Index>
+where
+    I: ArenaIndex,
+{
+    entries: Enumerate<slice::IterMut<'a, ArenaEntry<T, I>>>,
+    remaining: usize,
+    _phantom_type: PhantomData<I>,
+}
+
+impl<'a, T, I> Iterator for IterMut<'a, T, I>
+where
+    I: ArenaIndex,
+{
+    type Item = (I, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.entries.find_map(|(idx, entry)| match entry {
+            Entry::Occupied { value, generation } => Some((I::new(idx, *generation)?, value)),
+            _ => None,
+        })?;
+
+        self.remaining -= 1;
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, I> ExactSizeIterator for IterMut<'_, T, I> where I: ArenaIndex {}
+
+/// Draining iterator over the items allocated in an [`Arena`].
+pub struct Drain<'a, V, T, I = Index>
+where
+    V: Vector<ArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    arena: &'a mut Arena<V, T, I>,
+    cursor: usize,
+}
+
+impl<V, T, I> Iterator for Drain<'_, V, T, I>
+where
+    V: Vector<ArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    type Item = (I, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.arena.next_occupied(self.cursor)?;
+        self.cursor = index.idx() + 1;
+
+        Some((index, self.arena.remove(&index)?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.arena.len(), Some(self.arena.len()))
+    }
+}
+
+impl<V, T, I> ExactSizeIterator for Drain<'_, V, T, I>
+where
+    V: Vector<ArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+}
+
+impl<V, T, I> Drop for Drain<'_, V, T, I>
+where
+    V: Vector<ArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+impl<'a, V, T, I> IntoIterator for &'a Arena<V, T, I>
+where
+    V: Vector<ArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    type Item = (I, &'a T);
+
+    type IntoIter = Iter<'a, T, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, V, T, I> IntoIterator for &'a mut Arena<V, T, I>
+where
+    V: Vector<ArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    type Item = (I, &'a mut T);
+
+    type IntoIter = IterMut<'a, T, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
 }
 
 #[doc(hidden)]
 pub mod tests {
-    use super::{Arena, Entry, Index, Vector};
-    use core::{cmp::PartialEq, fmt::Debug};
+    use super::{
+        Arena, ArenaEntry, ArenaError, ArenaIndex, ArenaViolation, Entry, Generation, Index,
+        PackedIndex, ReuseStrategy, Vector,
+    };
+    use core::{
+        cmp::PartialEq,
+        fmt::Debug,
+        mem,
+        ops::{Deref, DerefMut},
+    };
+
+    /// [`Vector`] wrapper reporting, and reserving, one more item of capacity than the wrapped
+    /// vector can store, for exercising push failures on bounded vectors.
+    pub struct Overcommitted<V>(pub V);
+
+    impl<V> Deref for Overcommitted<V>
+    where
+        V: Deref,
+    {
+        type Target = V::Target;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<V> DerefMut for Overcommitted<V>
+    where
+        V: DerefMut,
+    {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+
+    impl<V, T> Vector<T> for Overcommitted<V>
+    where
+        V: Vector<T>,
+    {
+        type Error = V::Error;
+
+        fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+            self.0.reserve(additional.saturating_sub(1))
+        }
+
+        fn capacity(&self) -> usize {
+            self.0.capacity() + 1
+        }
+
+        fn push(&mut self, item: T) -> Result<(), Self::Error> {
+            self.0.push(item)
+        }
+
+        fn clear(&mut self) {
+            self.0.clear()
+        }
+
+        fn truncate(&mut self, len: usize) {
+            self.0.truncate(len)
+        }
+    }
 
     pub fn _test_arena_free_entries_init<T, V>(mut arena: Arena<V, T>)
     where
@@ -324,14 +1409,16 @@ pub mod tests {
                 assert_eq!(
                     entry,
                     &Entry::Free {
-                        next_free_idx: None
+                        next_free_idx: None,
+                        generation: 0,
                     }
                 )
             } else {
                 assert_eq!(
                     entry,
                     &Entry::Free {
-                        next_free_idx: Some(i + 1)
+                        next_free_idx: Some(i + 1),
+                        generation: 0,
                     }
                 )
             };
@@ -347,6 +1434,11 @@ pub mod tests {
 
         let old_capacity = arena.capacity();
 
+        arena.reserve(0).unwrap();
+
+        assert_eq!(arena.capacity(), old_capacity);
+        assert_eq!(arena.free_list_head, Some(0));
+
         const ADDITIONAL: usize = 5;
 
         let result = arena.reserve(ADDITIONAL);
@@ -366,27 +1458,90 @@ pub mod tests {
                 assert_eq!(
                     entry,
                     &Entry::Free {
-                        next_free_idx: Some(0)
+                        next_free_idx: Some(0),
+                        generation: 0,
                     }
                 )
             } else if i == old_capacity - 1 {
                 assert_eq!(
                     entry,
                     &Entry::Free {
-                        next_free_idx: None
+                        next_free_idx: None,
+                        generation: 0,
                     }
                 )
             } else {
                 assert_eq!(
                     entry,
                     &Entry::Free {
-                        next_free_idx: Some(i + 1)
+                        next_free_idx: Some(i + 1),
+                        generation: 0,
                     }
                 )
             };
         }
     }
 
+    pub fn _test_arena_reserve_rollback<V>(bounded_vec: V)
+    where
+        V: Vector<Entry<i32>>,
+    {
+        let mut arena = Arena::<_, i32>::try_with_vector(bounded_vec).unwrap();
+
+        let capacity = arena.capacity();
+
+        assert!(capacity >= 4, "Test not valid for arenas with capacity < 4");
+
+        arena.shrink_to(capacity / 2, |_, _, _| {});
+
+        let Arena {
+            entries_vec,
+            unmapped_generation,
+            free_list_head,
+            free_list_tail,
+            reuse_strategy,
+            len,
+            capacity: shrunk_capacity,
+            _phantom_type,
+        } = arena;
+
+        let mut arena = Arena {
+            entries_vec: Overcommitted(entries_vec),
+            unmapped_generation,
+            free_list_head,
+            free_list_tail,
+            reuse_strategy,
+            len,
+            capacity: shrunk_capacity,
+            _phantom_type,
+        };
+
+        assert_eq!(shrunk_capacity, capacity / 2);
+        arena.validate().unwrap();
+
+        match arena.reserve(capacity - shrunk_capacity + 1) {
+            Err(ArenaError::VectorError(_)) => {}
+            _ => unreachable!("Wrong error on reserve beyond the vector capacity."),
+        };
+
+        assert_eq!(arena.capacity(), shrunk_capacity);
+        assert_eq!(arena.entries_vec.len(), shrunk_capacity);
+        arena.validate().unwrap();
+
+        match arena.reserve(usize::MAX) {
+            Err(ArenaError::OutOfMemory) => {}
+            _ => unreachable!("Wrong error on overflowing reserve."),
+        };
+
+        assert_eq!(arena.capacity(), shrunk_capacity);
+        arena.validate().unwrap();
+
+        arena.reserve(capacity - shrunk_capacity).unwrap();
+
+        assert_eq!(arena.capacity(), capacity);
+        arena.validate().unwrap();
+    }
+
     pub fn _test_arena_insert<V>(mut arena: Arena<V, i32>)
     where
         V: Vector<Entry<i32>>,
@@ -411,7 +1566,7 @@ pub mod tests {
         assert_eq!(
             index_1.as_ref().unwrap(),
             &Index {
-                generation: 1,
+                generation: 0,
                 idx: 1
             }
         );
@@ -450,7 +1605,7 @@ pub mod tests {
             assert_eq!(
                 arena.insert(0).unwrap(),
                 Index {
-                    generation: possible_idx as u64,
+                    generation: 0,
                     idx: possible_idx
                 }
             )
@@ -516,14 +1671,12 @@ pub mod tests {
         let last_arena_len = arena.len();
         let remaining = arena.capacity() - last_arena_len;
 
-        let current_generation = index.generation + 1;
-
         for i in 0..remaining {
             let index = arena.insert(i as i32).unwrap();
             assert_eq!(
                 index,
                 Index {
-                    generation: current_generation + i as u64,
+                    generation: 0,
                     idx: last_arena_len + i
                 }
             );
@@ -535,7 +1688,7 @@ pub mod tests {
         while i < arena.capacity() {
             arena
                 .remove(&Index {
-                    generation: i as u64 + 1,
+                    generation: 0,
                     idx: i,
                 })
                 .unwrap();
@@ -551,7 +1704,7 @@ pub mod tests {
         while let Some(next_free) = free_idx {
             assert_eq!(next_free & 1, 1);
             free_idx = match arena.entries_vec[next_free] {
-                Entry::Free { next_free_idx } => next_free_idx,
+                Entry::Free { next_free_idx, .. } => next_free_idx,
                 _ => None,
             };
             free_position_count += 1;
@@ -563,4 +1716,699 @@ pub mod tests {
 
         assert!(arena.is_empty());
     }
+
+    pub fn _test_arena_shrink_to<V>(mut arena: Arena<V, i32>)
+    where
+        V: Vector<Entry<i32>>,
+    {
+        let capacity = arena.capacity();
+
+        assert!(capacity >= 4, "Test not valid for arena with capacity < 4");
+
+        arena.clear().unwrap();
+
+        for i in 0..capacity {
+            arena.insert(i as i32).unwrap();
+        }
+
+        // remove elements at even indices
+        for i in (0..capacity).step_by(2) {
+            arena
+                .remove(&Index {
+                    generation: 0,
+                    idx: i,
+                })
+                .unwrap();
+        }
+
+        let len = arena.len();
+
+        arena.shrink_to(capacity, |_, _, _| unreachable!("No relocation expected."));
+        assert_eq!(arena.capacity(), capacity);
+
+        let mut relocations = 0;
+
+        arena.shrink_to(0, |arena, old_index, new_index| {
+            assert!(old_index.idx >= len);
+            assert!(new_index.idx < len);
+
+            assert!(arena.get(&old_index).is_none());
+            assert_eq!(arena.get(&new_index), Some(&(old_index.idx as i32)));
+
+            relocations += 1;
+        });
+
+        assert_eq!(arena.capacity(), len);
+        assert_eq!(arena.len(), len);
+        assert_eq!(relocations, len / 2);
+        assert_eq!(arena.free_list_head, None);
+
+        for i in (1..len).step_by(2) {
+            assert_eq!(
+                arena.get(&Index {
+                    generation: 0,
+                    idx: i,
+                }),
+                Some(&(i as i32))
+            );
+        }
+
+        assert!(arena.insert(0).is_err());
+
+        arena.reserve(capacity - len).unwrap();
+
+        assert_eq!(arena.capacity(), capacity);
+
+        for _ in len..capacity {
+            arena.insert(0).unwrap();
+        }
+
+        assert_eq!(arena.len(), capacity);
+
+        arena.clear().unwrap();
+
+        assert!(arena.is_empty());
+    }
+
+    pub fn _test_arena_try_with_vector<T, V>(bounded_vec: V, test_vec: V)
+    where
+        V: Vector<Entry<T>>,
+    {
+        match Arena::<_, T>::try_with_vector(Overcommitted(bounded_vec)) {
+            Err(ArenaError::VectorError(_)) => {}
+            _ => unreachable!("Wrong error on overcommitted vector."),
+        };
+
+        let capacity = test_vec.capacity();
+
+        let arena = Arena::<_, T>::try_with_vector(test_vec).unwrap();
+
+        assert_eq!(arena.capacity(), capacity);
+        assert!(arena.is_empty());
+    }
+
+    pub fn _test_arena_generations<V>(mut arena: Arena<V, i32>)
+    where
+        V: Vector<Entry<i32>>,
+    {
+        let capacity = arena.capacity();
+
+        assert!(capacity >= 2, "Test not valid for arena with capacity < 2");
+
+        arena.clear().unwrap();
+
+        let index = arena.insert(0).unwrap();
+
+        arena.clear().unwrap();
+
+        let new_index = arena.insert(1).unwrap();
+
+        assert_eq!(new_index.idx, index.idx);
+        assert_ne!(new_index.generation, index.generation);
+
+        assert!(arena.get(&index).is_none());
+        assert_eq!(arena.get(&new_index), Some(&1));
+
+        // generations of released entries are not reused when the arena grows again
+        arena.clear().unwrap();
+
+        let mut last_index = arena.insert(0).unwrap();
+
+        for i in 1..capacity {
+            last_index = arena.insert(i as i32).unwrap();
+        }
+
+        assert_eq!(last_index.idx, capacity - 1);
+
+        arena.remove(&last_index).unwrap();
+        arena.shrink_to(capacity - 1, |_, _, _| {
+            unreachable!("No relocation expected.")
+        });
+
+        assert_eq!(arena.capacity(), capacity - 1);
+
+        arena.reserve(1).unwrap();
+
+        let regrown_index = arena.insert(0).unwrap();
+
+        assert_eq!(regrown_index.idx, last_index.idx);
+        assert_ne!(regrown_index.generation, last_index.generation);
+        assert!(arena.get(&last_index).is_none());
+
+        // replacing in place advances the generation
+        let len = arena.len();
+        let (replaced_index, old) = arena.replace(&regrown_index, 1).unwrap();
+
+        assert_eq!(old, 0);
+        assert_eq!(replaced_index.idx, regrown_index.idx);
+        assert_ne!(replaced_index.generation, regrown_index.generation);
+        assert!(arena.get(&regrown_index).is_none());
+        assert_eq!(arena.get(&replaced_index), Some(&1));
+        assert_eq!(arena.replace(&regrown_index, 2), None);
+        assert_eq!(arena.len(), len);
+        arena.validate().unwrap();
+
+        // generations wrap around on overflow
+        arena.clear().unwrap();
+
+        arena.entries_vec[0] = Entry::Occupied {
+            value: 0,
+            generation: u64::MAX,
+        };
+        arena.len = 1;
+
+        arena
+            .remove(&Index {
+                generation: u64::MAX,
+                idx: 0,
+            })
+            .unwrap();
+
+        assert!(matches!(
+            arena.entries_vec[0],
+            Entry::Free { generation: 0, .. }
+        ));
+    }
+
+    pub fn _test_arena_index_types<V, I>(mut arena: Arena<V, i32, I>)
+    where
+        V: Vector<ArenaEntry<i32, I>>,
+        I: ArenaIndex,
+    {
+        let capacity = arena.capacity();
+
+        assert!(capacity >= 2, "Test not valid for arena with capacity < 2");
+
+        arena.clear().unwrap();
+
+        let index = arena.insert(0).unwrap();
+
+        assert_eq!(index.idx(), 0);
+        assert_eq!(index.generation(), I::Generation::default());
+        assert_eq!(arena.get(&index), Some(&0));
+
+        assert_eq!(arena.remove(&index), Some(0));
+
+        let new_index = arena.insert(1).unwrap();
+
+        assert_eq!(new_index.idx(), 0);
+        assert_eq!(new_index.generation(), index.generation().next());
+        assert!(arena.get(&index).is_none());
+
+        for i in 1..capacity {
+            assert_eq!(arena.insert(i as i32).unwrap().idx(), i);
+        }
+
+        assert!(arena.insert(0).is_err());
+
+        arena.clear().unwrap();
+
+        assert!(arena.get(&new_index).is_none());
+    }
+
+    pub fn _test_packed_index() {
+        assert_eq!(mem::size_of::<PackedIndex>(), 8);
+        assert_eq!(mem::size_of::<Option<PackedIndex>>(), 8);
+
+        let index = PackedIndex::new(42, u32::MAX).unwrap();
+
+        assert_eq!(index.idx(), 42);
+        assert_eq!(index.generation(), u32::MAX);
+
+        let index = PackedIndex::new(u32::MAX as usize - 1, 0).unwrap();
+
+        assert_eq!(index.idx(), u32::MAX as usize - 1);
+        assert_eq!(index.generation(), 0);
+
+        assert!(PackedIndex::new(u32::MAX as usize, 0).is_none());
+
+        assert_eq!(u16::MAX.next(), 0);
+        assert_eq!(u32::MAX.next(), 0);
+        assert_eq!(u64::MAX.next(), 0);
+
+        assert_eq!(
+            Index::new(7, 3_u16),
+            Some(Index {
+                generation: 3,
+                idx: 7
+            })
+        );
+    }
+
+    pub fn _test_arena_iteration<V>(mut arena: Arena<V, i32>)
+    where
+        V: Vector<Entry<i32>>,
+    {
+        let capacity = arena.capacity();
+
+        assert!(capacity >= 4, "Test not valid for arena with capacity < 4");
+
+        arena.clear().unwrap();
+
+        assert_eq!(arena.iter().next(), None);
+        assert_eq!(arena.drain().next(), None);
+
+        for i in 0..capacity {
+            arena.insert(i as i32).unwrap();
+        }
+
+        // remove elements at odd indices
+        for i in (1..capacity).step_by(2) {
+            arena
+                .remove(&Index {
+                    generation: 0,
+                    idx: i,
+                })
+                .unwrap();
+        }
+
+        let len = arena.len();
+
+        assert_eq!(arena.iter().len(), len);
+
+        for (i, (index, value)) in arena.iter().enumerate() {
+            assert_eq!(
+                index,
+                Index {
+                    generation: 0,
+                    idx: 2 * i
+                }
+            );
+            assert_eq!(value, &(2 * i as i32));
+        }
+
+        for (_, value) in arena.iter_mut() {
+            *value *= 10;
+        }
+
+        for (index, value) in &arena {
+            assert_eq!(value, &(index.idx as i32 * 10));
+        }
+
+        arena.retain(|index, value| {
+            *value += 1;
+            index.idx % 4 == 0
+        });
+
+        assert_eq!(arena.len(), len.div_ceil(2));
+
+        for (index, value) in &mut arena {
+            assert_eq!(index.idx % 4, 0);
+            assert_eq!(value, &mut (index.idx as i32 * 10 + 1));
+        }
+
+        let len = arena.len();
+
+        let mut drain = arena.drain();
+
+        assert_eq!(drain.len(), len);
+
+        let (index, value) = drain.next().unwrap();
+
+        assert_eq!(index.idx, 0);
+        assert_eq!(value, 1);
+
+        drop(drain);
+
+        assert!(arena.is_empty());
+        assert_eq!(arena.iter().next(), None);
+        assert!(arena.get(&index).is_none());
+
+        for i in 0..capacity {
+            arena.insert(i as i32).unwrap();
+        }
+
+        assert_eq!(arena.drain().count(), capacity);
+        assert!(arena.is_empty());
+    }
+
+    pub fn _test_arena_insert_with<V>(mut arena: Arena<V, Index>)
+    where
+        V: Vector<Entry<Index>>,
+    {
+        let capacity = arena.capacity();
+
+        assert!(capacity >= 2, "Test not valid for arena with capacity < 2");
+
+        arena.clear().unwrap();
+
+        for _ in 0..capacity / 2 {
+            let index = arena.insert_with(|index| index).unwrap();
+            assert_eq!(arena.get(&index), Some(&index));
+        }
+
+        let index = arena.vacant_entry().unwrap().index();
+
+        assert_eq!(arena.len(), capacity / 2);
+        assert!(arena.get(&index).is_none());
+
+        let vacant_entry = arena.vacant_entry().unwrap();
+
+        assert_eq!(vacant_entry.index(), index);
+        assert_eq!(vacant_entry.insert(index), index);
+
+        assert_eq!(arena.len(), capacity / 2 + 1);
+        assert_eq!(arena.get(&index), Some(&index));
+
+        while arena.len() < capacity {
+            arena.insert_with(|index| index).unwrap();
+        }
+
+        for (index, value) in arena.iter() {
+            assert_eq!(&index, value);
+        }
+
+        assert!(arena.vacant_entry().is_err());
+
+        match arena.insert_with(|_| unreachable!("Item created on full arena.")) {
+            Err(ArenaError::OutOfMemory) => {}
+            _ => unreachable!("Wrong error on full arena."),
+        };
+
+        arena.remove(&index).unwrap();
+
+        let new_index = arena.insert_with(|index| index).unwrap();
+
+        assert_eq!(new_index.idx, index.idx);
+        assert_ne!(new_index.generation, index.generation);
+    }
+
+    pub fn _test_arena_get_many_mut<V>(mut arena: Arena<V, i32>)
+    where
+        V: Vector<Entry<i32>>,
+    {
+        let capacity = arena.capacity();
+
+        assert!(capacity >= 4, "Test not valid for arena with capacity < 4");
+
+        arena.clear().unwrap();
+
+        let index_0 = arena.insert(0).unwrap();
+        let index_1 = arena.insert(1).unwrap();
+        let index_2 = arena.insert(2).unwrap();
+
+        let (item_0, item_1) = arena.get2_mut(&index_0, &index_1).unwrap();
+        mem::swap(item_0, item_1);
+
+        assert_eq!(arena.get(&index_0), Some(&1));
+        assert_eq!(arena.get(&index_1), Some(&0));
+
+        let [item_2, item_0, item_1] = arena.get_many_mut(&[index_2, index_0, index_1]).unwrap();
+
+        *item_0 += 10;
+        *item_1 += 20;
+        *item_2 += 30;
+
+        assert_eq!(arena.get(&index_0), Some(&11));
+        assert_eq!(arena.get(&index_1), Some(&20));
+        assert_eq!(arena.get(&index_2), Some(&32));
+
+        assert!(arena.get_many_mut::<0>(&[]).unwrap().is_empty());
+
+        match arena.get2_mut(&index_0, &index_0) {
+            Err(ArenaError::AliasedIdx) => {}
+            _ => unreachable!("Wrong error on aliased indices."),
+        };
+
+        match arena.get_many_mut(&[index_0, index_1, index_0]) {
+            Err(ArenaError::AliasedIdx) => {}
+            _ => unreachable!("Wrong error on aliased indices."),
+        };
+
+        arena.remove(&index_1).unwrap();
+
+        match arena.get2_mut(&index_0, &index_1) {
+            Err(ArenaError::InvalidIdx) => {}
+            _ => unreachable!("Wrong error on stale index."),
+        };
+
+        let new_index_1 = arena.insert(1).unwrap();
+
+        assert_eq!(new_index_1.idx, index_1.idx);
+
+        match arena.get2_mut(&index_1, &new_index_1) {
+            Err(ArenaError::InvalidIdx) => {}
+            _ => unreachable!("Wrong error on stale index."),
+        };
+
+        let out_of_bounds = Index {
+            generation: 0,
+            idx: capacity,
+        };
+
+        match arena.get_many_mut(&[index_0, out_of_bounds]) {
+            Err(ArenaError::InvalidIdx) => {}
+            _ => unreachable!("Wrong error on out of bounds index."),
+        };
+
+        assert_eq!(arena.get(&index_0), Some(&11));
+        assert_eq!(arena.get(&new_index_1), Some(&1));
+    }
+
+    pub fn _test_arena_reuse_strategies<V>(mut arena: Arena<V, i32>)
+    where
+        V: Vector<Entry<i32>>,
+    {
+        let capacity = arena.capacity();
+
+        assert!(capacity >= 4, "Test not valid for arena with capacity < 4");
+
+        assert_eq!(arena.reuse_strategy(), ReuseStrategy::Lifo);
+
+        let index_at = |arena: &Arena<V, i32>, idx| {
+            arena
+                .iter()
+                .map(|(index, _)| index)
+                .find(|index| index.idx == idx)
+                .unwrap()
+        };
+
+        let reuse_order = |arena: &mut Arena<V, i32>, strategy| {
+            arena.clear().unwrap();
+            arena.set_reuse_strategy(strategy);
+
+            for i in 0..capacity {
+                arena.insert(i as i32).unwrap();
+            }
+
+            for idx in [2, 0, 1] {
+                arena.remove(&index_at(arena, idx)).unwrap();
+            }
+
+            let mut order = [0; 3];
+
+            arena.validate().unwrap();
+
+            for idx in order.iter_mut() {
+                *idx = arena.insert(0).unwrap().idx;
+            }
+
+            arena.validate().unwrap();
+
+            order
+        };
+
+        assert_eq!(reuse_order(&mut arena, ReuseStrategy::Lifo), [1, 0, 2]);
+        assert_eq!(reuse_order(&mut arena, ReuseStrategy::Fifo), [2, 0, 1]);
+        assert_eq!(
+            reuse_order(&mut arena, ReuseStrategy::LowestIndex),
+            [0, 1, 2]
+        );
+
+        // entries freed later are reused after the entries never allocated
+        arena.clear().unwrap();
+        arena.set_reuse_strategy(ReuseStrategy::Fifo);
+
+        let index_0 = arena.insert(0).unwrap();
+        arena.remove(&index_0).unwrap();
+
+        for i in 1..capacity {
+            assert_eq!(arena.insert(i as i32).unwrap().idx, i);
+        }
+
+        assert_eq!(arena.insert(0).unwrap().idx, 0);
+        assert!(arena.insert(0).is_err());
+
+        // switching to lowest index reuse sorts the free list
+        arena.clear().unwrap();
+        arena.set_reuse_strategy(ReuseStrategy::Lifo);
+
+        for i in 0..capacity {
+            arena.insert(i as i32).unwrap();
+        }
+
+        for idx in [3, 1, 2] {
+            arena.remove(&index_at(&arena, idx)).unwrap();
+        }
+
+        arena.set_reuse_strategy(ReuseStrategy::LowestIndex);
+
+        arena.remove(&index_at(&arena, 0)).unwrap();
+
+        for idx in 0..4 {
+            assert_eq!(arena.insert(idx as i32).unwrap().idx, idx);
+        }
+
+        assert_eq!(arena.len(), capacity);
+        assert_eq!(arena.free_list_head, None);
+        assert_eq!(arena.free_list_tail, None);
+    }
+
+    pub fn _test_arena_validate<V>(mut arena: Arena<V, i32>)
+    where
+        V: Vector<Entry<i32>>,
+    {
+        let capacity = arena.capacity();
+
+        assert!(capacity >= 4, "Test not valid for arena with capacity < 4");
+
+        arena.clear().unwrap();
+        arena.validate().unwrap();
+
+        let indices = [(); 4].map(|_| arena.insert(0).unwrap());
+
+        arena.remove(&indices[1]).unwrap();
+        arena.validate().unwrap();
+
+        arena.len += 1;
+        assert_eq!(
+            arena.validate(),
+            Err(ArenaViolation::LenMismatch {
+                len: 4,
+                occupied: 3
+            })
+        );
+        arena.len -= 1;
+
+        let free_list_head = arena.free_list_head;
+
+        arena.free_list_head = Some(indices[0].idx);
+        assert_eq!(
+            arena.validate(),
+            Err(ArenaViolation::FreeListBroken {
+                idx: indices[0].idx
+            })
+        );
+
+        arena.free_list_head = free_list_head.and_then(|idx| arena.free_list_next(idx));
+        assert_eq!(
+            arena.validate(),
+            Err(ArenaViolation::FreeListUnreachable {
+                reachable: capacity - 4,
+                free: capacity - 3
+            })
+        );
+
+        arena.free_list_head = free_list_head;
+        arena.validate().unwrap();
+
+        let free_list_tail = arena.free_list_tail;
+
+        arena.free_list_tail = free_list_head;
+        assert_eq!(
+            arena.validate(),
+            Err(ArenaViolation::FreeListTailMismatch {
+                tail: free_list_head,
+                last: free_list_tail
+            })
+        );
+
+        if let Some(tail_idx) = free_list_tail {
+            arena.set_free_list_next(tail_idx, free_list_head);
+        }
+        assert_eq!(
+            arena.validate(),
+            Err(ArenaViolation::FreeListCycle {
+                idx: free_list_head.unwrap()
+            })
+        );
+
+        arena.clear().unwrap();
+        arena.set_reuse_strategy(ReuseStrategy::LowestIndex);
+        arena.validate().unwrap();
+
+        arena.free_list_head = Some(1);
+        arena.set_free_list_next(1, Some(0));
+        arena.set_free_list_next(0, Some(2));
+        assert_eq!(
+            arena.validate(),
+            Err(ArenaViolation::FreeListUnsorted {
+                idx: 1,
+                next_idx: 0
+            })
+        );
+
+        arena.entries_vec[0] = Entry::Unmapped;
+        assert_eq!(
+            arena.validate(),
+            Err(ArenaViolation::UnmappedEntry { idx: 0 })
+        );
+
+        arena.capacity += 1;
+        assert_eq!(
+            arena.validate(),
+            Err(ArenaViolation::CapacityMismatch {
+                capacity: capacity + 1,
+                entries: capacity
+            })
+        );
+        arena.capacity -= 1;
+
+        arena.clear().unwrap();
+        arena.validate().unwrap();
+    }
+
+    pub fn _test_arena_compact<V>(mut arena: Arena<V, i32>)
+    where
+        V: Vector<Entry<i32>>,
+    {
+        let capacity = arena.capacity();
+
+        assert!(capacity >= 4, "Test not valid for arena with capacity < 4");
+
+        arena.clear().unwrap();
+
+        for i in 0..capacity {
+            arena.insert(i as i32).unwrap();
+        }
+
+        // remove the first half and every third element of the second half
+        for i in (0..capacity / 2).chain((capacity / 2..capacity).step_by(3)) {
+            arena
+                .remove(&Index {
+                    generation: 0,
+                    idx: i,
+                })
+                .unwrap();
+        }
+
+        let len = arena.len();
+
+        let mut remapped = 0;
+
+        arena.compact(|old_index, new_index| {
+            assert!(old_index.idx >= len);
+            assert!(new_index.idx < len);
+
+            remapped += 1;
+        });
+
+        assert_eq!(arena.capacity(), len);
+        assert_eq!(arena.len(), len);
+        assert_eq!(arena.free_list_head, None);
+        assert!(remapped > 0);
+
+        assert_eq!(arena.iter().len(), len);
+
+        for (index, _) in arena.iter() {
+            assert!(index.idx < len);
+        }
+
+        let mut remapped = 0;
+
+        arena.compact(|_, _| remapped += 1);
+
+        assert_eq!(remapped, 0);
+        assert_eq!(arena.capacity(), len);
+    }
 }
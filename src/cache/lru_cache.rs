@@ -38,24 +38,31 @@
 //!
 //! assert_eq!(cache.query(&-2).unwrap(), Lookup::Miss);
 //!
-//! // zero capacity LRUCache is unusable
+//! // fallible construction rejects zero capacity backing vectors
+//! match LRUCache::<_, i32, u64, AllocBTreeMap<_, _>>::try_with_backing_vector(Array::<_, 0_usize>::new()) {
+//!     Err(LRUCacheError::ZeroCapacity) => {}
+//!     _ => unreachable!("Wrong error on zero capacity."),
+//! };
+//!
+//! // zero capacity LRUCache passes every inserted pair through
 //! let mut cache = LRUCache::<_, i32, u64, AllocBTreeMap<_, _>>::with_backing_vector(Array::<_, 0_usize>::new());
 //!
-//! match cache.insert(0, 0) {
-//!     Err(LRUCacheError::ListUnderflow) => {}
-//!     _ => unreachable!("Wrong error on list underflow."),
-//! };
+//! assert_eq!(cache.insert(0, 0).unwrap(), Eviction::Block { key: 0, value: 0 });
+//! assert_eq!(cache.query(&0).unwrap(), Lookup::Miss);
 //!
 //! ```
 
 use crate::{
-    cache::{Cache, Eviction},
-    collections::list::{Link, LinkedList, LinkedListArenaEntry, ListError},
+    arena::{ArenaIndex, Index, IndexFor},
+    cache::{Admission, Cache, Eviction},
+    collections::list::{
+        Link, LinkedListArenaEntry, ListArena, ListError, ListViolation, Node, SharedList,
+    },
     map::Map,
     vector::Vector,
 };
 use core::{
-    fmt::{Debug, Display},
+    fmt::{self, Debug, Display},
     mem,
 };
 
@@ -64,95 +71,601 @@ use super::Lookup;
 extern crate alloc;
 
 /// A cache block containing a key value pair.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct Block<K, T> {
     pub key: K,
     pub value: T,
 }
 
+/// A [`Block`] as stored in an [`LRUCache`], along with whether it is pinned.
+#[derive(Clone, Copy, Debug)]
+pub struct PinnableBlock<K, T> {
+    block: Block<K, T>,
+    pinned: bool,
+}
+
 /// Alias representing block entries for storage in a generational arena.
-pub type LRUCacheBlockArenaEntry<K, T> = LinkedListArenaEntry<Block<K, T>>;
+pub type LRUCacheBlockArenaEntry<K, T, I = Index> = LinkedListArenaEntry<PinnableBlock<K, T>, I>;
+
+/// An opaque handle to a cache block in an [`LRUCache`].
+///
+/// Handles provide access to cache blocks without a [`Map`] lookup. Since a handle wraps the
+/// generational [`Link`] to the block, it becomes stale once the block is removed or evicted.
+/// Accessing a block with a stale handle yields `None`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EntryHandle<I = Index> {
+    link: Link<I>,
+}
 
 /// A generational [`Arena`](crate::arena::Arena) backed LRU cache implementation.
 ///
 /// This [`Cache`] implementation always evicts the least-recently-used (LRU) key/value pair. It
-/// uses a [`LinkedList`] for storing the underlying cache block entries to maintain the order
-/// in which they were inserted into the cache.
+/// uses a [`SharedList`] for storing the underlying cache block entries to maintain the order
+/// in which they were used.
 ///
-/// It uses a generational [`Arena`](crate::arena::Arena) for allocating the underlying
-/// [`LinkedList`] which stores the cache blocks. It uses a [`Map`] for maintaining the mapping
-/// from keys to the nodes storing the respective cache blocks in the [`LinkedList`].
+/// It uses a generational [`Arena`](crate::arena::Arena) backed [`ListArena`] for allocating the
+/// nodes which store the cache blocks. It uses a [`Map`] for maintaining the mapping from keys to
+/// the nodes storing the respective cache blocks.
+///
+/// Blocks may be pinned with [`LRUCache::pin`] to exempt them from eviction. Pinned blocks
+/// still count towards the capacity of the cache. They are kept in a second [`SharedList`]
+/// allocated from the same [`ListArena`], so that eviction never has to skip over them. Blocks
+/// are ordered by use only among blocks with the same pin state.
 ///
 /// ### Type parameters
-/// - `V: Vector<LRUCacheBlockArenaEntry<K, T>>`
-///     Used as the backing vector for the underlying [`Arena`](crate::arena::Arena).
+/// - `V: Vector<LRUCacheBlockArenaEntry<K, T, I>>`
+///   Used as the backing vector for the underlying [`Arena`](crate::arena::Arena).
 /// - `K`
-///     The Key type.
+///   The Key type.
 /// - `V`
-///     The Value type.
-/// - `M: Map<K, Link>`
-///     Used to store a mapping from the keys to links in the linked list.
+///   The Value type.
+/// - `M: Map<K, Link<I>>`
+///   Used to store a mapping from the keys to links in the linked list.
+/// - `I: ArenaIndex`
+///   The index type used for links in the linked list. Defaults to [`Index`]. Use
+///   [`PackedIndex`](crate::arena::PackedIndex) to reduce the size of links and map values.
 ///
-pub struct LRUCache<V, K, T, M> {
-    block_list: LinkedList<V, Block<K, T>>,
+pub struct LRUCache<V, K, T, M, I = Index>
+where
+    I: ArenaIndex,
+{
+    blocks: ListArena<V, PinnableBlock<K, T>, I>,
+    lru_list: SharedList<PinnableBlock<K, T>, I>,
+    pinned_list: SharedList<PinnableBlock<K, T>, I>,
     block_refs: M,
 
     capacity: usize,
 }
 
-impl<V, K, T, M> LRUCache<V, K, T, M>
+impl<V, K, T, M, I> LRUCache<V, K, T, M, I>
 where
-    V: Vector<LRUCacheBlockArenaEntry<K, T>>,
-    M: Map<K, Link>,
+    V: Vector<LRUCacheBlockArenaEntry<K, T, I>>,
+    I: ArenaIndex,
+    M: Map<K, Link<I>>,
 {
-    /// Returns the least recently used key/value pair.
+    /// Returns the least recently used key/value pair which is not pinned, i.e. the one
+    /// evicted next.
     pub fn least_recent(&self) -> Option<(&K, &T)> {
-        let block = self.block_list.peek_front()?;
+        let PinnableBlock { block, .. } = self.lru_list.peek_front(&self.blocks)?;
         Some((&block.key, &block.value))
     }
 
-    /// Returns the most recently used key/value pair.
+    /// Returns the most recently used key/value pair which is not pinned.
     pub fn most_recent(&self) -> Option<(&K, &T)> {
-        let block = self.block_list.peek_back()?;
+        let PinnableBlock { block, .. } = self.lru_list.peek_back(&self.blocks)?;
+        Some((&block.key, &block.value))
+    }
+
+    /// Returns an [`EntryHandle`] to the cache block associated with the given key.
+    pub fn handle(&self, key: &K) -> Option<EntryHandle<I>> {
+        self.block_refs.get(key).map(|&link| EntryHandle { link })
+    }
+
+    /// Returns the key/value pair referenced by the given [`EntryHandle`] without marking it
+    /// as recently used.
+    ///
+    /// Returns `None` if the handle is stale.
+    pub fn get_by_handle(&self, handle: &EntryHandle<I>) -> Option<(&K, &T)> {
+        let PinnableBlock { block, .. } = self.blocks.get(&handle.link)?;
         Some((&block.key, &block.value))
     }
+
+    /// Returns the key/value pair referenced by the given [`EntryHandle`] after marking it as
+    /// the most recently used.
+    ///
+    /// Returns `None` if the handle is stale.
+    pub fn touch_by_handle(&mut self, handle: &EntryHandle<I>) -> Option<(&K, &T)> {
+        let (blocks, list) = self.split_list_of(&handle.link)?;
+        list.shift_push_back(blocks, &handle.link)?;
+        self.get_by_handle(handle)
+    }
+
+    /// Removes the key/value pair referenced by the given [`EntryHandle`] from this cache.
+    ///
+    /// Returns `None` if the handle is stale.
+    pub fn remove_by_handle(&mut self, handle: &EntryHandle<I>) -> Option<(K, T)> {
+        let block = self.unlink_block(&handle.link)?;
+        Some(self.forget_block(block))
+    }
+
+    /// Moves the blocks stored beyond the capacity of this cache into free entries within it,
+    /// and releases the memory beyond the capacity from the underlying [`Vector`].
+    ///
+    /// This reclaims memory after lowering the capacity with [`Cache::shrink`]. The [`Map`]
+    /// values for the moved blocks are updated, while any [`EntryHandle`] to them becomes stale.
+    pub fn compact(&mut self) {
+        let Self {
+            blocks,
+            lru_list,
+            pinned_list,
+            block_refs,
+            capacity,
+        } = self;
+
+        blocks.shrink_to(
+            *capacity,
+            &mut [lru_list, pinned_list],
+            |PinnableBlock { block, .. }, _, new_link| {
+                if let Some(link) = block_refs.get_mut(&block.key) {
+                    *link = new_link;
+                }
+            },
+        );
+    }
+
+    /// Removes and returns the least recently used key/value pair which is not pinned.
+    pub fn pop_least_recent(&mut self) -> Option<(K, T)> {
+        let PinnableBlock { block, .. } = self.lru_list.pop_front(&mut self.blocks)?;
+        Some(self.forget_block(block))
+    }
+
+    /// Removes and returns the most recently used key/value pair which is not pinned.
+    pub fn pop_most_recent(&mut self) -> Option<(K, T)> {
+        let PinnableBlock { block, .. } = self.lru_list.pop_back(&mut self.blocks)?;
+        Some(self.forget_block(block))
+    }
+
+    /// Marks the block associated with the given key as the most recently used, without
+    /// accessing its value.
+    ///
+    /// Returns whether a block associated with the given key was found.
+    pub fn promote(&mut self, key: &K) -> Result<bool, LRUCacheError<V::Error, M::Error>> {
+        match self.block_refs.get(key) {
+            Some(&link) => self
+                .split_list_of(&link)
+                .and_then(|(blocks, list)| list.shift_push_back(blocks, &link))
+                .map(|_| true)
+                .ok_or(LRUCacheError::MapListInconsistent),
+            None => Ok(false),
+        }
+    }
+
+    /// Marks the block associated with the given key as the least recently used, so that it is
+    /// evicted next unless it is pinned.
+    ///
+    /// Returns whether a block associated with the given key was found.
+    pub fn demote(&mut self, key: &K) -> Result<bool, LRUCacheError<V::Error, M::Error>> {
+        match self.block_refs.get(key) {
+            Some(&link) => self
+                .split_list_of(&link)
+                .and_then(|(blocks, list)| list.shift_push_front(blocks, &link))
+                .map(|_| true)
+                .ok_or(LRUCacheError::MapListInconsistent),
+            None => Ok(false),
+        }
+    }
+
+    /// Pins the block associated with the given key, exempting it from eviction. The block is
+    /// marked as the most recently used among pinned blocks.
+    ///
+    /// Returns whether a block associated with the given key was found.
+    pub fn pin(&mut self, key: &K) -> Result<bool, LRUCacheError<V::Error, M::Error>> {
+        self.set_pinned(key, true)
+    }
+
+    /// Unpins the block associated with the given key, making it eligible for eviction again.
+    /// The block is marked as the most recently used among unpinned blocks.
+    ///
+    /// Returns whether a block associated with the given key was found.
+    pub fn unpin(&mut self, key: &K) -> Result<bool, LRUCacheError<V::Error, M::Error>> {
+        self.set_pinned(key, false)
+    }
+
+    /// Returns whether the block associated with the given key is pinned.
+    pub fn is_pinned(&self, key: &K) -> bool {
+        self.block_refs
+            .get(key)
+            .and_then(|link| self.blocks.get(link))
+            .is_some_and(|entry| entry.pinned)
+    }
+
+    /// Returns the number of pinned blocks in this cache.
+    pub fn pinned_len(&self) -> usize {
+        self.pinned_list.len()
+    }
+
+    /// Moves the block associated with the given key to the back of the list for the given pin
+    /// state, in `O(1)`.
+    fn set_pinned(
+        &mut self,
+        key: &K,
+        pinned: bool,
+    ) -> Result<bool, LRUCacheError<V::Error, M::Error>> {
+        let link = match self.block_refs.get(key) {
+            Some(&link) => link,
+            None => return Ok(false),
+        };
+
+        let entry = self
+            .blocks
+            .get_mut(&link)
+            .ok_or(LRUCacheError::MapListInconsistent)?;
+
+        if entry.pinned == pinned {
+            return Ok(true);
+        }
+
+        entry.pinned = pinned;
+
+        let (from, to) = match pinned {
+            true => (&mut self.lru_list, &mut self.pinned_list),
+            false => (&mut self.pinned_list, &mut self.lru_list),
+        };
+
+        from.transfer_to_back(&mut self.blocks, &link, to)
+            .map(|_| true)
+            .ok_or(LRUCacheError::MapListInconsistent)
+    }
+
+    /// Returns the underlying [`ListArena`] along with the list holding the block at the given
+    /// [`Link`].
+    #[allow(clippy::type_complexity)]
+    fn split_list_of(
+        &mut self,
+        link: &Link<I>,
+    ) -> Option<(
+        &mut ListArena<V, PinnableBlock<K, T>, I>,
+        &mut SharedList<PinnableBlock<K, T>, I>,
+    )> {
+        let list = match self.blocks.get(link)?.pinned {
+            true => &mut self.pinned_list,
+            false => &mut self.lru_list,
+        };
+
+        Some((&mut self.blocks, list))
+    }
+
+    /// Removes the block at the given [`Link`] from the list holding it.
+    fn unlink_block(&mut self, link: &Link<I>) -> Option<Block<K, T>> {
+        let (blocks, list) = self.split_list_of(link)?;
+        let PinnableBlock { block, .. } = list.remove(blocks, link)?;
+        Some(block)
+    }
+
+    /// Retains only the key/value pairs for which the given predicate returns `true`.
+    ///
+    /// Blocks are visited in LRU order, unpinned blocks first. The predicate is applied to
+    /// pinned blocks as well.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut T) -> bool,
+    {
+        self.drain_filter(|key, value| !f(key, value))
+            .for_each(drop);
+    }
+
+    /// Removes all key/value pairs from this cache, returning them as an iterator in LRU order,
+    /// unpinned blocks first.
+    ///
+    /// Key/value pairs which are not consumed are removed when the iterator is dropped.
+    pub fn drain(&mut self) -> Drain<'_, V, K, T, M, I> {
+        Drain { cache: self }
+    }
+
+    /// Returns an iterator which removes and yields the key/value pairs for which the given
+    /// predicate returns `true`, in LRU order, unpinned blocks first.
+    ///
+    /// Key/value pairs which are not visited before the iterator is dropped remain in this
+    /// cache.
+    pub fn drain_filter<F>(&mut self, pred: F) -> DrainFilter<'_, V, K, T, M, I, F>
+    where
+        F: FnMut(&K, &mut T) -> bool,
+    {
+        let cursor = self.lru_list.peek_front_link().copied();
+        let pinned_cursor = self.pinned_list.peek_front_link().copied();
+
+        DrainFilter {
+            cache: self,
+            cursor,
+            pinned_cursor,
+            pred,
+        }
+    }
+
+    /// Returns the [`Link`] to the least recently used block which is not pinned.
+    fn least_recent_evictable(&self) -> Result<Link<I>, LRUCacheError<V::Error, M::Error>> {
+        match self.lru_list.peek_front_link() {
+            Some(&link) => Ok(link),
+            None if self.blocks.is_empty() => Err(LRUCacheError::ListUnderflow),
+            None => Err(LRUCacheError::AllBlocksPinned),
+        }
+    }
+
+    /// Removes the least recently used block which is not pinned from this cache.
+    fn evict_least_recent(&mut self) -> Result<(K, T), LRUCacheError<V::Error, M::Error>> {
+        let link = self.least_recent_evictable()?;
+
+        let PinnableBlock { block, .. } = self
+            .lru_list
+            .remove(&mut self.blocks, &link)
+            .ok_or(LRUCacheError::MapListInconsistent)?;
+
+        Ok(self.forget_block(block))
+    }
+
+    /// Checks the internal consistency of this cache.
+    ///
+    /// Validates the underlying [`ListArena`] along with both lists allocated from it, and
+    /// cross-checks them against the [`Map`]: every block must be mapped to its link by its
+    /// key, and the map must hold no other entries. Also verifies the length against the
+    /// capacity and the pin state of every block against the list holding it. Returns the first
+    /// [`LRUCacheViolation`] found.
+    ///
+    /// An inconsistency between the map and the list is otherwise only reported as
+    /// [`LRUCacheError::MapListInconsistent`] by the operation running into it.
+    pub fn validate(&self) -> Result<(), LRUCacheViolation<I>> {
+        self.blocks
+            .validate()
+            .map_err(LRUCacheViolation::ListViolation)?;
+
+        for list in [&self.lru_list, &self.pinned_list] {
+            list.validate(&self.blocks)
+                .map_err(LRUCacheViolation::ListViolation)?;
+        }
+
+        let (nodes, len) = (
+            self.blocks.len(),
+            self.lru_list.len() + self.pinned_list.len(),
+        );
+
+        if nodes != len {
+            return Err(LRUCacheViolation::ListViolation(
+                ListViolation::UnlinkedNodes { nodes, linked: len },
+            ));
+        }
+
+        let capacity = self.capacity;
+
+        if len > capacity {
+            return Err(LRUCacheViolation::CapacityExceeded { len, capacity });
+        }
+
+        for (pinned, list) in [(false, &self.lru_list), (true, &self.pinned_list)] {
+            for (link, entry) in list.iter_links(&self.blocks) {
+                if self.block_refs.get(&entry.block.key) != Some(&link) {
+                    return Err(LRUCacheViolation::BlockUnmapped { link });
+                }
+
+                if entry.pinned != pinned {
+                    return Err(LRUCacheViolation::PinStateMismatch { link, pinned });
+                }
+            }
+        }
+
+        let map_len = self.block_refs.len();
+
+        if map_len != len {
+            return Err(LRUCacheViolation::MapLenMismatch { map_len, len });
+        }
+
+        Ok(())
+    }
+
+    /// Removes the mapping for the given block, which has been removed from its list.
+    fn forget_block(&mut self, block: Block<K, T>) -> (K, T) {
+        let Block { key, value } = block;
+
+        self.block_refs.remove(&key);
+
+        (key, value)
+    }
 }
 
-impl<V, K, T, M> LRUCache<V, K, T, M>
+impl<V, K, T, M, I> LRUCache<V, K, T, M, I>
 where
-    V: Vector<LRUCacheBlockArenaEntry<K, T>>,
-    M: Map<K, Link>,
+    V: Vector<LRUCacheBlockArenaEntry<K, T, I>>,
+    I: ArenaIndex,
+    M: Map<K, Link<I>>,
+    K: Copy,
 {
-    /// Creates an [`LRUCache`] instance with the given the backing [`Vector`] and [`Map`]
-    /// implementation instances.
-    pub fn with_backing_vector_and_map(vector: V, map: M) -> Self {
-        let block_list = LinkedList::with_backing_vector(vector);
-        let capacity = block_list.capacity();
+    /// Inserts the given key/value pair and returns the [`Link`] to the block storing it
+    /// along with the resulting [`Eviction`].
+    #[allow(clippy::type_complexity)]
+    fn insert_and_link(
+        &mut self,
+        key: K,
+        value: T,
+    ) -> Result<(Link<I>, Eviction<K, T>), LRUCacheError<V::Error, M::Error>> {
+        if let Some(&link) = self.block_refs.get(&key) {
+            let (blocks, list) = self
+                .split_list_of(&link)
+                .ok_or(LRUCacheError::MapListInconsistent)?;
+
+            list.shift_push_back(blocks, &link)
+                .ok_or(LRUCacheError::MapListInconsistent)?;
+
+            let entry = blocks
+                .get_mut(&link)
+                .ok_or(LRUCacheError::MapListInconsistent)?;
+
+            return Ok((
+                link,
+                Eviction::Value(mem::replace(&mut entry.block.value, value)),
+            ));
+        }
+
+        let block = PinnableBlock {
+            block: Block { key, value },
+            pinned: false,
+        };
+
+        if !self.is_maxed() {
+            let link = self
+                .lru_list
+                .push_back(&mut self.blocks, block)
+                .map_err(LRUCacheError::ListError)?;
+
+            if let Err(error) = self.block_refs.insert(key, link) {
+                self.lru_list.remove(&mut self.blocks, &link);
+                return Err(LRUCacheError::MapError(error));
+            }
+
+            return Ok((link, Eviction::None));
+        }
+
+        let victim_link = self.least_recent_evictable()?;
+
+        let victim_key = self
+            .blocks
+            .get(&victim_link)
+            .map(|entry| entry.block.key)
+            .ok_or(LRUCacheError::MapListInconsistent)?;
+
+        // Update the map before touching the list, so that a failed map insertion can be rolled
+        // back. The victim's mapping is removed first to make room in fixed capacity maps, and
+        // the new key temporarily maps to the victim's link.
+        self.block_refs.remove(&victim_key);
+
+        if let Err(error) = self.block_refs.insert(key, victim_link) {
+            return match self.block_refs.insert(victim_key, victim_link) {
+                Ok(_) => Err(LRUCacheError::MapError(error)),
+                Err(_) => Err(LRUCacheError::MapListInconsistent),
+            };
+        }
+
+        // The new block takes the victim's node in place, so that no allocation can fail after
+        // the victim is evicted. Its link changes, which makes handles to the victim stale.
+        let (link, victim_block) = self
+            .lru_list
+            .replace(&mut self.blocks, &victim_link, block)
+            .ok_or(LRUCacheError::MapListInconsistent)?;
+
+        self.lru_list
+            .shift_push_back(&mut self.blocks, &link)
+            .ok_or(LRUCacheError::MapListInconsistent)?;
+
+        *self
+            .block_refs
+            .get_mut(&key)
+            .ok_or(LRUCacheError::MapListInconsistent)? = link;
+
+        let Block { key, value } = victim_block.block;
+
+        Ok((link, Eviction::Block { key, value }))
+    }
+
+    /// Inserts the given key/value pair into this cache and returns an [`EntryHandle`] to the
+    /// block storing it, along with the resulting [`Eviction`].
+    ///
+    /// Returns [`LRUCacheError::ZeroCapacity`] on a zero capacity cache, since no block can be
+    /// stored in it.
+    #[allow(clippy::type_complexity)]
+    pub fn insert_with_handle(
+        &mut self,
+        key: K,
+        value: T,
+    ) -> Result<(EntryHandle<I>, Eviction<K, T>), LRUCacheError<V::Error, M::Error>> {
+        if self.capacity() == 0 {
+            return Err(LRUCacheError::ZeroCapacity);
+        }
+
+        self.insert_and_link(key, value)
+            .map(|(link, eviction)| (EntryHandle { link }, eviction))
+    }
+}
+
+impl<V, K, T, M, I> LRUCache<V, K, T, M, I>
+where
+    V: Vector<LRUCacheBlockArenaEntry<K, T, I>>,
+    I: IndexFor<Node<PinnableBlock<K, T>, I>>,
+    M: Map<K, Link<I>>,
+{
+    fn with_blocks_and_map(blocks: ListArena<V, PinnableBlock<K, T>, I>, map: M) -> Self {
+        let capacity = blocks.capacity();
+        let (lru_list, pinned_list) = (blocks.new_list(), blocks.new_list());
 
         Self {
-            block_list,
+            blocks,
+            lru_list,
+            pinned_list,
             block_refs: map,
             capacity,
         }
     }
+
+    /// Creates an [`LRUCache`] instance with the given the backing [`Vector`] and [`Map`]
+    /// implementation instances.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying [`ListArena`] cannot be initialized. Use
+    /// [`LRUCache::try_with_backing_vector_and_map`] for a non-panicking alternative.
+    pub fn with_backing_vector_and_map(vector: V, map: M) -> Self {
+        Self::with_blocks_and_map(ListArena::with_backing_vector(vector), map)
+    }
+
+    /// Creates an [`LRUCache`] instance with the given the backing [`Vector`] and [`Map`]
+    /// implementation instances.
+    ///
+    /// Returns [`LRUCacheError::ZeroCapacity`] if the given [`Vector`] has zero capacity, or
+    /// [`LRUCacheError::ListError`] if the underlying [`ListArena`] cannot be initialized.
+    pub fn try_with_backing_vector_and_map(
+        vector: V,
+        map: M,
+    ) -> Result<Self, LRUCacheError<V::Error, M::Error>> {
+        if vector.capacity() == 0 {
+            return Err(LRUCacheError::ZeroCapacity);
+        }
+
+        let blocks =
+            ListArena::try_with_backing_vector(vector).map_err(LRUCacheError::ListError)?;
+
+        Ok(Self::with_blocks_and_map(blocks, map))
+    }
 }
 
-impl<V, K, T, M> LRUCache<V, K, T, M>
+impl<V, K, T, M, I> LRUCache<V, K, T, M, I>
 where
-    V: Vector<LRUCacheBlockArenaEntry<K, T>>,
-    M: Map<K, Link> + Default,
+    V: Vector<LRUCacheBlockArenaEntry<K, T, I>>,
+    I: IndexFor<Node<PinnableBlock<K, T>, I>>,
+    M: Map<K, Link<I>> + Default,
 {
     /// Creates an [`LRUCache`] instance with the given [`Vector`] implementation instance
     /// and the default [`Map`] implementation value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying [`ListArena`] cannot be initialized. Use
+    /// [`LRUCache::try_with_backing_vector`] for a non-panicking alternative.
     pub fn with_backing_vector(vector: V) -> Self {
         Self::with_backing_vector_and_map(vector, M::default())
     }
+
+    /// Creates an [`LRUCache`] instance with the given [`Vector`] implementation instance
+    /// and the default [`Map`] implementation value.
+    ///
+    /// Returns [`LRUCacheError::ZeroCapacity`] if the given [`Vector`] has zero capacity, or
+    /// [`LRUCacheError::ListError`] if the underlying [`ListArena`] cannot be initialized.
+    pub fn try_with_backing_vector(vector: V) -> Result<Self, LRUCacheError<V::Error, M::Error>> {
+        Self::try_with_backing_vector_and_map(vector, M::default())
+    }
 }
 
-impl<V, K, T, M> Default for LRUCache<V, K, T, M>
+impl<V, K, T, M, I> Default for LRUCache<V, K, T, M, I>
 where
-    V: Vector<LRUCacheBlockArenaEntry<K, T>> + Default,
-    M: Map<K, Link> + Default,
+    V: Vector<LRUCacheBlockArenaEntry<K, T, I>> + Default,
+    I: IndexFor<Node<PinnableBlock<K, T>, I>>,
+    M: Map<K, Link<I>> + Default,
 {
     fn default() -> Self {
         Self::with_backing_vector(V::default())
@@ -174,6 +687,13 @@ pub enum LRUCacheError<VE, ME> {
 
     /// Used when there is an error on an operation in the underlying map..
     MapError(ME),
+
+    /// Used when a block needs to be evicted, but every block in the cache is pinned.
+    AllBlocksPinned,
+
+    /// Used when constructing a cache from a backing vector with zero capacity, or when
+    /// requesting a handle to a block in a zero capacity cache.
+    ZeroCapacity,
 }
 
 impl<VE, ME> Display for LRUCacheError<VE, ME>
@@ -186,61 +706,99 @@ where
     }
 }
 
+/// Inconsistency in the internal state of an [`LRUCache`], reported by [`LRUCache::validate`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LRUCacheViolation<I = Index> {
+    /// Used when the underlying list is inconsistent.
+    ListViolation(ListViolation<I>),
+
+    /// Used when the cache holds more blocks than its capacity.
+    CapacityExceeded { len: usize, capacity: usize },
+
+    /// Used when the key of the block at the given link is not mapped to the link.
+    BlockUnmapped { link: Link<I> },
+
+    /// Used when the map holds a different number of entries than the list.
+    MapLenMismatch { map_len: usize, len: usize },
+
+    /// Used when the pin state of the block at the given link differs from the pin state of the
+    /// list holding it.
+    PinStateMismatch { link: Link<I>, pinned: bool },
+}
+
+impl<I> Display for LRUCacheViolation<I>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Dumps the internal state of an [`LRUCache`], including its underlying [`ListArena`] and
+/// lists. The [`Map`] is left out, as it is not required to implement [`Debug`].
+impl<V, K, T, M, I> Debug for LRUCache<V, K, T, M, I>
+where
+    V: Vector<LRUCacheBlockArenaEntry<K, T, I>>,
+    K: Debug,
+    T: Debug,
+    I: ArenaIndex,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LRUCache")
+            .field("capacity", &self.capacity)
+            .field("blocks", &self.blocks)
+            .field("lru_list", &self.lru_list)
+            .field("pinned_list", &self.pinned_list)
+            .finish_non_exhaustive()
+    }
+}
+
 #[allow(unused)]
-impl<V, K, T, M> Cache<K, T> for LRUCache<V, K, T, M>
+impl<V, K, T, M, I> Cache<K, T> for LRUCache<V, K, T, M, I>
 where
-    V: Vector<LRUCacheBlockArenaEntry<K, T>>,
-    M: Map<K, Link>,
+    V: Vector<LRUCacheBlockArenaEntry<K, T, I>>,
+    I: ArenaIndex,
+    M: Map<K, Link<I>>,
     K: Copy,
 {
     type Error = LRUCacheError<V::Error, M::Error>;
 
     fn insert(&mut self, key: K, value: T) -> Result<Eviction<K, T>, Self::Error> {
-        if let Some(link) = self.block_refs.get(&key) {
-            self.block_list
-                .shift_push_back(link)
-                .ok_or(Self::Error::MapListInconsistent)?;
-
-            let block = self
-                .block_list
-                .get_mut(link)
-                .ok_or(Self::Error::MapListInconsistent)?;
-
-            return Ok(Eviction::Value(mem::replace(&mut block.value, value)));
+        // A zero capacity cache holds no blocks, so the pair is evicted right away.
+        if self.capacity() == 0 {
+            return Ok(Eviction::Block { key, value });
         }
 
-        let eviction = if self.is_maxed() {
-            let Block { key, value } = self
-                .block_list
-                .pop_front()
-                .ok_or(Self::Error::ListUnderflow)?;
-
-            self.block_refs.remove(&key);
+        self.insert_and_link(key, value)
+            .map(|(_, eviction)| eviction)
+    }
 
-            Eviction::Block { key, value }
-        } else {
-            Eviction::None
-        };
+    fn try_insert(&mut self, key: K, value: T) -> Result<Admission<K, T>, Self::Error> {
+        if self.block_refs.get(&key).is_none() && self.is_maxed() {
+            return Ok(Admission::Rejected { key, value });
+        }
 
-        let link = self
-            .block_list
-            .push_back(Block { key, value })
-            .map_err(Self::Error::ListError)?;
+        self.insert(key, value).map(Admission::Admitted)
+    }
 
-        self.block_refs
-            .insert(key, link)
-            .map_err(Self::Error::MapError)?;
+    fn insert_if_absent(&mut self, key: K, value: T) -> Result<Admission<K, T>, Self::Error> {
+        if self.block_refs.get(&key).is_some() {
+            return Ok(Admission::Rejected { key, value });
+        }
 
-        Ok(eviction)
+        self.insert(key, value).map(Admission::Admitted)
     }
 
     fn remove(&mut self, key: &K) -> Result<Lookup<T>, Self::Error> {
-        match self.block_refs.remove(key) {
-            Some(link) => self
-                .block_list
-                .remove(&link)
-                .map(|x| Lookup::Hit(x.value))
-                .ok_or(Self::Error::MapListInconsistent),
+        match self.block_refs.get(key) {
+            Some(&link) => {
+                let block = self
+                    .unlink_block(&link)
+                    .ok_or(Self::Error::MapListInconsistent)?;
+
+                Ok(Lookup::Hit(self.forget_block(block).1))
+            }
             _ => Ok(Lookup::Miss),
         }
     }
@@ -250,13 +808,16 @@ where
             return Ok(());
         }
 
-        while self.len() > new_capacity {
-            let Block { key, value } = self
-                .block_list
-                .pop_front()
-                .ok_or(Self::Error::ListUnderflow)?;
+        let excess = self.len().saturating_sub(new_capacity);
+
+        // Check upfront that enough blocks can be evicted, so that a failed shrink leaves this
+        // cache unchanged.
+        if excess > self.lru_list.len() {
+            return Err(Self::Error::AllBlocksPinned);
+        }
 
-            self.block_refs.remove(&key);
+        for _ in 0..excess {
+            self.evict_least_recent()?;
         }
 
         self.capacity = new_capacity;
@@ -265,8 +826,8 @@ where
     }
 
     fn reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
-        self.block_list
-            .reserve(additional)
+        self.blocks
+            .reserve(self.capacity() + additional - self.len())
             .map_err(Self::Error::ListError)?;
 
         self.capacity += additional;
@@ -274,16 +835,37 @@ where
         Ok(())
     }
 
+    /// Resizes this cache to the given capacity.
+    ///
+    /// On shrinking, least recently used blocks are evicted and the memory for the excess blocks
+    /// is released from the underlying [`Vector`]. Blocks stored beyond the new capacity are
+    /// moved within it, which makes any [`EntryHandle`] to them stale.
+    fn resize(&mut self, new_capacity: usize) -> Result<(), Self::Error> {
+        let capacity = self.capacity();
+
+        if new_capacity >= capacity {
+            return self.reserve(new_capacity - capacity);
+        }
+
+        self.shrink(new_capacity)?;
+        self.compact();
+
+        Ok(())
+    }
+
     fn query(&mut self, key: &K) -> Result<Lookup<&T>, Self::Error> {
         match self.block_refs.get(key) {
-            Some(link) => {
-                self.block_list
-                    .shift_push_back(link)
+            Some(&link) => {
+                let (blocks, list) = self
+                    .split_list_of(&link)
                     .ok_or(Self::Error::MapListInconsistent)?;
 
-                self.block_list
-                    .get(link)
-                    .map(|x| Lookup::Hit(&x.value))
+                list.shift_push_back(blocks, &link)
+                    .ok_or(Self::Error::MapListInconsistent)?;
+
+                self.blocks
+                    .get(&link)
+                    .map(|entry| Lookup::Hit(&entry.block.value))
                     .ok_or(Self::Error::MapListInconsistent)
             }
             _ => Ok(Lookup::Miss),
@@ -295,28 +877,120 @@ where
     }
 
     fn len(&self) -> usize {
-        self.block_list.len()
+        self.lru_list.len() + self.pinned_list.len()
     }
 
     fn is_empty(&self) -> bool {
-        self.block_list.is_empty()
+        self.blocks.is_empty()
     }
 
     fn clear(&mut self) -> Result<(), Self::Error> {
-        self.block_list.clear().map_err(Self::Error::ListError)?;
-        self.block_refs.clear().map_err(Self::Error::MapError)?;
+        self.lru_list.clear(&mut self.blocks);
+        self.pinned_list.clear(&mut self.blocks);
 
-        Ok(())
+        self.block_refs.clear().map_err(Self::Error::MapError)
+    }
+}
+
+/// Draining iterator over the key/value pairs in an [`LRUCache`].
+///
+/// Created with [`LRUCache::drain`].
+pub struct Drain<'a, V, K, T, M, I = Index>
+where
+    V: Vector<LRUCacheBlockArenaEntry<K, T, I>>,
+    I: ArenaIndex,
+    M: Map<K, Link<I>>,
+{
+    cache: &'a mut LRUCache<V, K, T, M, I>,
+}
+
+impl<V, K, T, M, I> Iterator for Drain<'_, V, K, T, M, I>
+where
+    V: Vector<LRUCacheBlockArenaEntry<K, T, I>>,
+    I: ArenaIndex,
+    M: Map<K, Link<I>>,
+{
+    type Item = (K, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let LRUCache {
+            blocks,
+            lru_list,
+            pinned_list,
+            ..
+        } = &mut *self.cache;
+
+        let PinnableBlock { block, .. } = lru_list
+            .pop_front(blocks)
+            .or_else(|| pinned_list.pop_front(blocks))?;
+
+        Some(self.cache.forget_block(block))
+    }
+}
+
+impl<V, K, T, M, I> Drop for Drain<'_, V, K, T, M, I>
+where
+    V: Vector<LRUCacheBlockArenaEntry<K, T, I>>,
+    I: ArenaIndex,
+    M: Map<K, Link<I>>,
+{
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+/// Iterator removing the key/value pairs matching a predicate from an [`LRUCache`].
+///
+/// Created with [`LRUCache::drain_filter`].
+pub struct DrainFilter<'a, V, K, T, M, I, F>
+where
+    V: Vector<LRUCacheBlockArenaEntry<K, T, I>>,
+    I: ArenaIndex,
+    M: Map<K, Link<I>>,
+    F: FnMut(&K, &mut T) -> bool,
+{
+    cache: &'a mut LRUCache<V, K, T, M, I>,
+    cursor: Option<Link<I>>,
+    pinned_cursor: Option<Link<I>>,
+    pred: F,
+}
+
+impl<V, K, T, M, I, F> Iterator for DrainFilter<'_, V, K, T, M, I, F>
+where
+    V: Vector<LRUCacheBlockArenaEntry<K, T, I>>,
+    I: ArenaIndex,
+    M: Map<K, Link<I>>,
+    F: FnMut(&K, &mut T) -> bool,
+{
+    type Item = (K, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(link) = self.cursor.take().or_else(|| self.pinned_cursor.take()) {
+            self.cursor = self.cache.blocks.next_link(&link).copied();
+
+            let PinnableBlock { block, .. } = self.cache.blocks.get_mut(&link)?;
+
+            if (self.pred)(&block.key, &mut block.value) {
+                let block = self.cache.unlink_block(&link)?;
+                return Some(self.cache.forget_block(block));
+            }
+        }
+
+        None
     }
 }
 
 #[doc(hidden)]
 pub mod tests {
+    extern crate alloc;
 
     use super::{
-        Cache, Eviction, LRUCache, LRUCacheBlockArenaEntry, LRUCacheError, Link, Lookup, Map,
-        Vector,
+        Admission, Cache, EntryHandle, Eviction, LRUCache, LRUCacheBlockArenaEntry, LRUCacheError,
+        LRUCacheViolation, Link, ListViolation, Lookup, Map, Vector,
     };
+    use crate::{arena::PackedIndex, map::impls::alloc_btree_map::AllocBTreeMap};
+    use alloc::vec::Vec;
+    use core::mem;
 
     pub fn _test_cache_correctness<VX, VY, M>(zero_capacity_vec: VX, test_vec: VY)
     where
@@ -334,10 +1008,12 @@ pub mod tests {
 
         assert!(cache.is_empty());
 
-        match cache.insert(0, 0) {
-            Err(LRUCacheError::ListUnderflow) => {}
-            _ => unreachable!("Wrong error on list underflow."),
-        };
+        assert_eq!(
+            cache.insert(0, 0).unwrap(),
+            Eviction::Block { key: 0, value: 0 }
+        );
+
+        assert!(cache.is_empty());
 
         let mut cache = LRUCache::<_, _, _, M>::with_backing_vector(test_vec);
 
@@ -417,9 +1093,14 @@ pub mod tests {
 
         assert!(cache.is_maxed());
 
-        match cache.insert(0, 0) {
-            Err(LRUCacheError::ListUnderflow) => {}
-            _ => unreachable!("Wrong error on list underflow."),
+        assert_eq!(
+            cache.insert(0, 0).unwrap(),
+            Eviction::Block { key: 0, value: 0 }
+        );
+
+        match cache.insert_with_handle(0, 0) {
+            Err(LRUCacheError::ZeroCapacity) => {}
+            _ => unreachable!("Wrong error on zero capacity."),
         };
 
         assert!(cache.is_empty());
@@ -437,4 +1118,846 @@ pub mod tests {
 
         assert!(cache.is_empty());
     }
+
+    pub fn _test_cache_entry_handles<V, M>(test_vec: V)
+    where
+        V: Vector<LRUCacheBlockArenaEntry<usize, usize>>,
+        M: Map<usize, Link> + Default,
+    {
+        let mut cache = LRUCache::<_, _, _, M>::with_backing_vector(test_vec);
+
+        let capacity = cache.capacity();
+
+        assert!(
+            capacity > 3,
+            "Too small capacity: {} to run meaningful tests.",
+            capacity
+        );
+
+        let (handle_0, eviction) = cache.insert_with_handle(0, 0).unwrap();
+        assert_eq!(eviction, Eviction::None);
+
+        for i in 1..capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+
+        assert_eq!(cache.handle(&0), Some(handle_0));
+        assert_eq!(cache.handle(&capacity), None);
+
+        assert_eq!(cache.get_by_handle(&handle_0), Some((&0, &0)));
+        assert_eq!(cache.least_recent().unwrap(), (&0, &0));
+
+        assert_eq!(cache.touch_by_handle(&handle_0), Some((&0, &0)));
+        assert_eq!(cache.most_recent().unwrap(), (&0, &0));
+
+        let (handle_1, eviction) = cache.insert_with_handle(0, 42).unwrap();
+        assert_eq!(eviction, Eviction::Value(0));
+        assert_eq!(handle_1, handle_0);
+        assert_eq!(cache.get_by_handle(&handle_0), Some((&0, &42)));
+
+        let handle_1 = cache.handle(&1).unwrap();
+
+        assert_eq!(
+            cache.insert(capacity, capacity).unwrap(),
+            Eviction::Block { key: 1, value: 1 }
+        );
+
+        assert_eq!(cache.get_by_handle(&handle_1), None);
+        assert_eq!(cache.touch_by_handle(&handle_1), None);
+        assert_eq!(cache.remove_by_handle(&handle_1), None);
+
+        assert_eq!(cache.remove_by_handle(&handle_0), Some((0, 42)));
+        assert_eq!(cache.get_by_handle(&handle_0), None);
+        assert_eq!(cache.query(&0).unwrap(), Lookup::Miss);
+
+        assert_eq!(cache.len(), capacity - 1);
+
+        // handles remain stale across clears
+        let handle_2 = cache.handle(&2).unwrap();
+
+        cache.clear().unwrap();
+
+        for i in 0..capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+
+        assert_eq!(cache.get_by_handle(&handle_2), None);
+        assert_eq!(
+            cache.get_by_handle(&cache.handle(&2).unwrap()),
+            Some((&2, &2))
+        );
+    }
+
+    pub fn _test_cache_pinning<V, M>(test_vec: V)
+    where
+        V: Vector<LRUCacheBlockArenaEntry<usize, usize>>,
+        M: Map<usize, Link> + Default,
+    {
+        let mut cache = LRUCache::<_, _, _, M>::with_backing_vector(test_vec);
+
+        let capacity = cache.capacity();
+
+        assert!(
+            capacity > 3,
+            "Too small capacity: {} to run meaningful tests.",
+            capacity
+        );
+
+        for i in 0..capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+
+        assert!(cache.pin(&0).unwrap());
+        assert!(cache.pin(&0).unwrap());
+        assert!(cache.pin(&1).unwrap());
+        assert!(!cache.pin(&capacity).unwrap());
+
+        assert!(cache.is_pinned(&0));
+        assert!(!cache.is_pinned(&2));
+        assert_eq!(cache.pinned_len(), 2);
+
+        assert_eq!(
+            cache.insert(capacity, capacity).unwrap(),
+            Eviction::Block { key: 2, value: 2 }
+        );
+
+        assert_eq!(cache.least_recent().unwrap(), (&3, &3));
+
+        assert_eq!(cache.query(&0).unwrap(), Lookup::Hit(&0));
+        assert_eq!(cache.most_recent().unwrap(), (&capacity, &capacity));
+
+        assert!(cache.unpin(&0).unwrap());
+        assert_eq!(cache.pinned_len(), 1);
+        assert_eq!(cache.most_recent().unwrap(), (&0, &0));
+
+        assert!(cache.demote(&0).unwrap());
+
+        assert_eq!(
+            cache.insert(capacity + 1, capacity + 1).unwrap(),
+            Eviction::Block { key: 0, value: 0 }
+        );
+
+        assert_eq!(cache.remove(&1).unwrap(), Lookup::Hit(1));
+        assert_eq!(cache.pinned_len(), 0);
+
+        cache.clear().unwrap();
+
+        for i in 0..capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+            assert!(cache.pin(&i).unwrap());
+        }
+
+        match cache.insert(capacity, capacity) {
+            Err(LRUCacheError::AllBlocksPinned) => {}
+            _ => unreachable!("Wrong error on eviction with all blocks pinned."),
+        };
+
+        assert_eq!(cache.least_recent(), None);
+        assert_eq!(cache.pop_least_recent(), None);
+        assert_eq!(cache.len(), capacity);
+
+        assert_eq!(cache.insert(0, 42).unwrap(), Eviction::Value(0));
+        assert!(cache.is_pinned(&0));
+
+        match cache.shrink(0) {
+            Err(LRUCacheError::AllBlocksPinned) => {}
+            _ => unreachable!("Wrong error on shrink with all blocks pinned."),
+        };
+
+        cache.clear().unwrap();
+
+        assert_eq!(cache.pinned_len(), 0);
+        assert!(cache.is_empty());
+    }
+
+    pub fn _test_cache_batch_operations<V, M>(test_vec: V)
+    where
+        V: Vector<LRUCacheBlockArenaEntry<usize, usize>> + Default,
+        M: Map<usize, Link> + Default,
+    {
+        let mut cache = LRUCache::<_, _, _, M>::with_backing_vector(test_vec);
+
+        let capacity = cache.capacity();
+
+        assert!(
+            capacity > 3,
+            "Too small capacity: {} to run meaningful tests.",
+            capacity
+        );
+
+        let mut evictions = Vec::new();
+
+        cache
+            .insert_many((0..capacity + 2).map(|i| (i, i)), &mut evictions)
+            .unwrap();
+
+        assert_eq!(evictions.len(), capacity + 2);
+        assert!(evictions[..capacity].iter().all(|x| x == &Eviction::None));
+        assert_eq!(evictions[capacity], Eviction::Block { key: 0, value: 0 });
+        assert_eq!(
+            evictions[capacity + 1],
+            Eviction::Block { key: 1, value: 1 }
+        );
+
+        let mut lookups = Vec::new();
+
+        cache
+            .query_many([1, 2, 3].iter(), |&key, lookup| match lookup {
+                Lookup::Hit(&value) => lookups.push((key, Lookup::Hit(value))),
+                Lookup::Miss => lookups.push((key, Lookup::Miss)),
+            })
+            .unwrap();
+
+        assert_eq!(
+            lookups,
+            [(1, Lookup::Miss), (2, Lookup::Hit(2)), (3, Lookup::Hit(3))]
+        );
+
+        assert_eq!(cache.most_recent().unwrap(), (&3, &3));
+
+        let mut lookups = Vec::new();
+
+        cache.remove_many([0, 2, 3].iter(), &mut lookups).unwrap();
+
+        assert_eq!(lookups, [Lookup::Miss, Lookup::Hit(2), Lookup::Hit(3)]);
+        assert_eq!(cache.len(), capacity - 2);
+
+        let mut evictions = Vec::new();
+
+        cache
+            .insert_many((0..capacity).map(|i| (i, i + 1)), &mut evictions)
+            .unwrap();
+
+        assert_eq!(evictions.len(), capacity);
+        assert_eq!(cache.len(), capacity);
+        assert_eq!(cache.least_recent().unwrap(), (&0, &1));
+        assert_eq!(cache.most_recent().unwrap(), (&(capacity - 1), &capacity));
+    }
+
+    pub fn _test_cache_retain_drain<V, M>(test_vec: V)
+    where
+        V: Vector<LRUCacheBlockArenaEntry<usize, usize>>,
+        M: Map<usize, Link> + Default,
+    {
+        let mut cache = LRUCache::<_, _, _, M>::with_backing_vector(test_vec);
+
+        let capacity = cache.capacity();
+
+        assert!(
+            capacity > 3,
+            "Too small capacity: {} to run meaningful tests.",
+            capacity
+        );
+
+        for i in 0..capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+
+        cache.pin(&1).unwrap();
+
+        cache.retain(|&key, value| {
+            *value += 1;
+            key & 1 == 0
+        });
+
+        assert_eq!(cache.len(), capacity.div_ceil(2));
+        assert_eq!(cache.pinned_len(), 0);
+        assert_eq!(cache.query(&1).unwrap(), Lookup::Miss);
+        assert_eq!(cache.query(&2).unwrap(), Lookup::Hit(&3));
+        assert_eq!(cache.least_recent().unwrap(), (&0, &1));
+
+        let drained: Vec<_> = cache.drain_filter(|&key, _| key % 4 == 0).collect();
+
+        assert!(drained
+            .iter()
+            .all(|&(key, value)| key % 4 == 0 && value == key + 1));
+        assert_eq!(drained.first(), Some(&(0, 1)));
+        assert_eq!(cache.query(&0).unwrap(), Lookup::Miss);
+        assert_eq!(cache.len(), capacity.div_ceil(2) - drained.len());
+
+        let remaining = cache.len();
+
+        let drained: Vec<_> = cache.drain().collect();
+
+        assert_eq!(drained.len(), remaining);
+        assert_eq!(drained.last(), Some(&(2, 3)));
+        assert!(cache.is_empty());
+        assert_eq!(cache.query(&2).unwrap(), Lookup::Miss);
+
+        for i in 0..capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+
+        assert_eq!(cache.drain().next(), Some((0, 0)));
+
+        assert!(cache.is_empty());
+        assert_eq!(cache.query(&1).unwrap(), Lookup::Miss);
+
+        for i in 0..capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+    }
+
+    pub fn _test_cache_conditional_inserts<V, M>(test_vec: V)
+    where
+        V: Vector<LRUCacheBlockArenaEntry<usize, usize>>,
+        M: Map<usize, Link> + Default,
+    {
+        let mut cache = LRUCache::<_, _, _, M>::with_backing_vector(test_vec);
+
+        let capacity = cache.capacity();
+
+        assert!(
+            capacity > 3,
+            "Too small capacity: {} to run meaningful tests.",
+            capacity
+        );
+
+        for i in 0..capacity {
+            assert_eq!(
+                cache.try_insert(i, i).unwrap(),
+                Admission::Admitted(Eviction::None)
+            );
+        }
+
+        assert!(cache.is_maxed());
+
+        assert_eq!(
+            cache.try_insert(capacity, capacity).unwrap(),
+            Admission::Rejected {
+                key: capacity,
+                value: capacity
+            }
+        );
+
+        assert_eq!(cache.query(&capacity).unwrap(), Lookup::Miss);
+        assert_eq!(cache.least_recent().unwrap(), (&0, &0));
+
+        assert_eq!(
+            cache.try_insert(0, 42).unwrap(),
+            Admission::Admitted(Eviction::Value(0))
+        );
+
+        assert_eq!(cache.most_recent().unwrap(), (&0, &42));
+        assert_eq!(cache.least_recent().unwrap(), (&1, &1));
+
+        assert_eq!(
+            cache.insert_if_absent(1, 42).unwrap(),
+            Admission::Rejected { key: 1, value: 42 }
+        );
+
+        assert_eq!(cache.least_recent().unwrap(), (&1, &1));
+
+        assert_eq!(
+            cache.insert_if_absent(capacity, capacity).unwrap(),
+            Admission::Admitted(Eviction::Block { key: 1, value: 1 })
+        );
+
+        assert_eq!(cache.most_recent().unwrap(), (&capacity, &capacity));
+
+        cache.remove(&capacity).unwrap();
+
+        assert_eq!(
+            cache.try_insert(capacity + 1, capacity + 1).unwrap(),
+            Admission::Admitted(Eviction::None)
+        );
+
+        assert!(cache.is_maxed());
+    }
+
+    pub fn _test_cache_promote_demote_pop<V, M>(test_vec: V)
+    where
+        V: Vector<LRUCacheBlockArenaEntry<usize, usize>>,
+        M: Map<usize, Link> + Default,
+    {
+        let mut cache = LRUCache::<_, _, _, M>::with_backing_vector(test_vec);
+
+        let capacity = cache.capacity();
+
+        assert!(
+            capacity > 3,
+            "Too small capacity: {} to run meaningful tests.",
+            capacity
+        );
+
+        assert_eq!(cache.pop_least_recent(), None);
+        assert_eq!(cache.pop_most_recent(), None);
+        assert!(!cache.promote(&0).unwrap());
+        assert!(!cache.demote(&0).unwrap());
+
+        for i in 0..capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+
+        assert!(cache.promote(&0).unwrap());
+        assert_eq!(cache.most_recent().unwrap(), (&0, &0));
+        assert_eq!(cache.least_recent().unwrap(), (&1, &1));
+
+        assert!(cache.demote(&2).unwrap());
+        assert_eq!(cache.least_recent().unwrap(), (&2, &2));
+
+        assert_eq!(
+            cache.insert(capacity, capacity).unwrap(),
+            Eviction::Block { key: 2, value: 2 }
+        );
+
+        cache.pin(&1).unwrap();
+
+        assert_eq!(cache.pop_least_recent(), Some((3, 3)));
+        assert_eq!(cache.pinned_len(), 1);
+        assert_eq!(cache.query(&3).unwrap(), Lookup::Miss);
+
+        assert_eq!(cache.pop_most_recent(), Some((capacity, capacity)));
+        assert_eq!(cache.query(&capacity).unwrap(), Lookup::Miss);
+
+        assert_eq!(cache.most_recent().unwrap(), (&0, &0));
+        assert_eq!(cache.len(), capacity - 2);
+
+        while cache.pop_least_recent().is_some() {}
+
+        assert_eq!(cache.len(), 1);
+        assert!(cache.unpin(&1).unwrap());
+        assert_eq!(cache.pop_least_recent(), Some((1, 1)));
+
+        assert!(cache.is_empty());
+        assert_eq!(cache.query(&0).unwrap(), Lookup::Miss);
+    }
+
+    /// A [`Map`] with a fixed capacity, which additionally rejects inserting a chosen key.
+    struct FixedCapacityMap {
+        inner: AllocBTreeMap<usize, Link>,
+        capacity: usize,
+        rejected_key: Option<usize>,
+    }
+
+    impl Map<usize, Link> for FixedCapacityMap {
+        type Error = ();
+
+        fn insert(&mut self, key: usize, value: Link) -> Result<Option<Link>, Self::Error> {
+            let is_new = self.inner.get(&key).is_none();
+
+            if Some(key) == self.rejected_key || (is_new && self.inner.len() == self.capacity) {
+                return Err(());
+            }
+
+            self.inner.insert(key, value).map_err(|_| ())
+        }
+
+        fn get(&self, key: &usize) -> Option<&Link> {
+            self.inner.get(key)
+        }
+
+        fn get_mut(&mut self, key: &usize) -> Option<&mut Link> {
+            self.inner.get_mut(key)
+        }
+
+        fn remove(&mut self, key: &usize) -> Option<Link> {
+            self.inner.remove(key)
+        }
+
+        fn clear(&mut self) -> Result<(), Self::Error> {
+            self.inner.clear().map_err(|_| ())
+        }
+
+        fn is_empty(&self) -> bool {
+            self.inner.is_empty()
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            Some(self.capacity)
+        }
+
+        fn len(&self) -> usize {
+            self.inner.len()
+        }
+    }
+
+    pub fn _test_cache_transactional_operations<V>(test_vec: V)
+    where
+        V: Vector<LRUCacheBlockArenaEntry<usize, usize>>,
+    {
+        let map = FixedCapacityMap {
+            inner: AllocBTreeMap::new(),
+            capacity: test_vec.capacity() - 1,
+            rejected_key: None,
+        };
+
+        let mut cache = LRUCache::with_backing_vector_and_map(test_vec, map);
+
+        let capacity = cache.capacity();
+
+        assert!(
+            capacity > 3,
+            "Too small capacity: {} to run meaningful tests.",
+            capacity
+        );
+
+        for i in 0..capacity - 1 {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+
+        match cache.insert(capacity, capacity) {
+            Err(LRUCacheError::MapError(())) => {}
+            _ => unreachable!("Wrong error on map insertion failure."),
+        };
+
+        assert_eq!(cache.len(), capacity - 1);
+        assert!(cache.handle(&capacity).is_none());
+        assert_eq!(cache.least_recent().unwrap(), (&0, &0));
+        assert_eq!(
+            cache.most_recent().unwrap(),
+            (&(capacity - 2), &(capacity - 2))
+        );
+
+        cache.block_refs.capacity = capacity;
+
+        assert_eq!(
+            cache.insert(capacity - 1, capacity - 1).unwrap(),
+            Eviction::None
+        );
+
+        assert!(cache.is_maxed());
+
+        let handle_0 = cache.handle(&0).unwrap();
+
+        cache.block_refs.rejected_key = Some(capacity);
+
+        match cache.insert(capacity, capacity) {
+            Err(LRUCacheError::MapError(())) => {}
+            _ => unreachable!("Wrong error on map insertion failure."),
+        };
+
+        assert_eq!(cache.len(), capacity);
+        assert_eq!(cache.block_refs.len(), capacity);
+        assert!(cache.handle(&capacity).is_none());
+        assert_eq!(cache.handle(&0), Some(handle_0));
+        assert_eq!(cache.get_by_handle(&handle_0), Some((&0, &0)));
+        assert_eq!(cache.least_recent().unwrap(), (&0, &0));
+        assert_eq!(
+            cache.most_recent().unwrap(),
+            (&(capacity - 1), &(capacity - 1))
+        );
+
+        cache.block_refs.rejected_key = None;
+        cache.block_refs.capacity = capacity - 1;
+
+        match cache.insert(capacity, capacity) {
+            Err(LRUCacheError::MapListInconsistent) => {}
+            _ => unreachable!("Wrong error on failed map rollback."),
+        };
+
+        assert_eq!(cache.len(), capacity);
+        assert_eq!(cache.get_by_handle(&handle_0), Some((&0, &0)));
+        assert_eq!(
+            cache.validate(),
+            Err(LRUCacheViolation::BlockUnmapped {
+                link: handle_0.link
+            })
+        );
+
+        cache.block_refs.capacity = capacity;
+        cache.block_refs.insert(0, handle_0.link).unwrap();
+        cache.validate().unwrap();
+
+        assert_eq!(
+            cache.insert(capacity, capacity).unwrap(),
+            Eviction::Block { key: 0, value: 0 }
+        );
+
+        assert_eq!(cache.get_by_handle(&handle_0), None);
+        assert_eq!(cache.query(&capacity).unwrap(), Lookup::Hit(&capacity));
+
+        for i in 2..=capacity {
+            cache.pin(&i).unwrap();
+        }
+
+        match cache.shrink(0) {
+            Err(LRUCacheError::AllBlocksPinned) => {}
+            _ => unreachable!("Wrong error on shrink with pinned blocks."),
+        };
+
+        assert_eq!(cache.len(), capacity);
+        assert_eq!(cache.capacity(), capacity);
+
+        cache.shrink(capacity - 1).unwrap();
+
+        assert_eq!(cache.len(), capacity - 1);
+        assert_eq!(cache.query(&1).unwrap(), Lookup::Miss);
+
+        cache.reserve(0).unwrap();
+
+        assert_eq!(cache.capacity(), capacity - 1);
+    }
+
+    pub fn _test_cache_resize<V, M>(test_vec: V)
+    where
+        V: Vector<LRUCacheBlockArenaEntry<usize, usize>>,
+        M: Map<usize, Link> + Default,
+    {
+        let mut cache = LRUCache::<_, _, _, M>::with_backing_vector(test_vec);
+
+        let capacity = cache.capacity();
+
+        assert!(
+            capacity > 3,
+            "Too small capacity: {} to run meaningful tests.",
+            capacity
+        );
+
+        for i in 0..capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+
+        let new_capacity = capacity / 2;
+
+        cache.resize(new_capacity).unwrap();
+
+        assert_eq!(cache.capacity(), new_capacity);
+        assert_eq!(cache.len(), new_capacity);
+        assert_eq!(cache.blocks.capacity(), new_capacity);
+        assert!(cache.is_maxed());
+
+        assert_eq!(
+            cache.query(&(capacity - new_capacity - 1)).unwrap(),
+            Lookup::Miss
+        );
+
+        for i in capacity - new_capacity..capacity {
+            assert_eq!(cache.query(&i).unwrap(), Lookup::Hit(&i));
+
+            let handle = cache.handle(&i).unwrap();
+            assert_eq!(cache.get_by_handle(&handle), Some((&i, &i)));
+        }
+
+        assert_eq!(
+            cache.insert(capacity, capacity).unwrap(),
+            Eviction::Block {
+                key: capacity - new_capacity,
+                value: capacity - new_capacity
+            }
+        );
+
+        cache.resize(capacity).unwrap();
+
+        assert_eq!(cache.capacity(), capacity);
+        assert_eq!(cache.len(), new_capacity);
+
+        for i in 0..capacity - new_capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+
+        assert!(cache.is_maxed());
+
+        cache.resize(capacity).unwrap();
+
+        assert_eq!(cache.capacity(), capacity);
+        assert!(cache.is_maxed());
+    }
+
+    pub fn _test_cache_compact<V, M>(test_vec: V)
+    where
+        V: Vector<LRUCacheBlockArenaEntry<usize, usize>>,
+        M: Map<usize, Link> + Default,
+    {
+        let mut cache = LRUCache::<_, _, _, M>::with_backing_vector(test_vec);
+
+        let capacity = cache.capacity();
+
+        assert!(
+            capacity > 3,
+            "Too small capacity: {} to run meaningful tests.",
+            capacity
+        );
+
+        for i in 0..capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+
+        let new_capacity = capacity / 2;
+
+        cache.shrink(new_capacity).unwrap();
+
+        assert_eq!(cache.blocks.capacity(), capacity);
+
+        let handle = cache.handle(&(capacity - 1)).unwrap();
+
+        cache.compact();
+
+        assert_eq!(cache.capacity(), new_capacity);
+        assert_eq!(cache.blocks.capacity(), new_capacity);
+        assert_eq!(cache.get_by_handle(&handle), None);
+
+        for i in capacity - new_capacity..capacity {
+            assert_eq!(cache.query(&i).unwrap(), Lookup::Hit(&i));
+        }
+
+        assert_eq!(
+            cache.least_recent().unwrap(),
+            (&(capacity - new_capacity), &(capacity - new_capacity))
+        );
+        assert_eq!(
+            cache.most_recent().unwrap(),
+            (&(capacity - 1), &(capacity - 1))
+        );
+
+        cache.compact();
+
+        assert_eq!(cache.blocks.capacity(), new_capacity);
+        assert_eq!(cache.len(), new_capacity);
+    }
+
+    pub fn _test_cache_fallible_construction<VX, VY, M>(zero_capacity_vec: VX, test_vec: VY)
+    where
+        VX: Vector<LRUCacheBlockArenaEntry<usize, usize>>,
+        VY: Vector<LRUCacheBlockArenaEntry<usize, usize>>,
+        M: Map<usize, Link> + Default,
+    {
+        assert_eq!(
+            zero_capacity_vec.capacity(),
+            0,
+            "Zero capacity vector provider yielded vector of non zero capacity."
+        );
+
+        match LRUCache::<_, _, _, M>::try_with_backing_vector(zero_capacity_vec) {
+            Err(LRUCacheError::ZeroCapacity) => {}
+            _ => unreachable!("Wrong error on zero capacity."),
+        };
+
+        let capacity = test_vec.capacity();
+
+        let mut cache = LRUCache::<_, _, _, M>::try_with_backing_vector(test_vec).unwrap();
+
+        assert_eq!(cache.capacity(), capacity);
+        assert!(cache.is_empty());
+
+        assert_eq!(cache.insert(0, 0).unwrap(), Eviction::None);
+        assert_eq!(cache.query(&0).unwrap(), Lookup::Hit(&0));
+    }
+
+    pub fn _test_cache_packed_index<V, M>(test_vec: V)
+    where
+        V: Vector<LRUCacheBlockArenaEntry<usize, usize, PackedIndex>>,
+        M: Map<usize, Link<PackedIndex>> + Default,
+    {
+        assert_eq!(
+            mem::size_of::<Option<Link<PackedIndex>>>(),
+            mem::size_of::<Link<PackedIndex>>()
+        );
+        assert_eq!(
+            mem::size_of::<Option<EntryHandle<PackedIndex>>>(),
+            mem::size_of::<EntryHandle<PackedIndex>>()
+        );
+
+        assert_eq!(mem::size_of::<Link<PackedIndex>>(), 8);
+
+        let mut cache = LRUCache::<_, _, _, M, PackedIndex>::with_backing_vector(test_vec);
+
+        let capacity = cache.capacity();
+
+        assert!(
+            capacity > 1,
+            "Too small capacity: {} to run meaningful tests.",
+            capacity
+        );
+
+        let (handle_0, _) = cache.insert_with_handle(0, 0).unwrap();
+
+        for i in 1..capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+
+        assert_eq!(cache.get_by_handle(&handle_0), Some((&0, &0)));
+
+        assert_eq!(
+            cache.insert(capacity, capacity).unwrap(),
+            Eviction::Block { key: 0, value: 0 }
+        );
+
+        assert_eq!(cache.get_by_handle(&handle_0), None);
+        assert_eq!(cache.query(&capacity).unwrap(), Lookup::Hit(&capacity));
+        assert_eq!(cache.least_recent().unwrap(), (&1, &1));
+    }
+
+    pub fn _test_cache_validate<V, M>(test_vec: V)
+    where
+        V: Vector<LRUCacheBlockArenaEntry<usize, usize>>,
+        M: Map<usize, Link> + Default,
+    {
+        let mut cache = LRUCache::<_, _, _, M>::with_backing_vector(test_vec);
+
+        let capacity = cache.capacity();
+
+        assert!(
+            capacity > 3,
+            "Too small capacity: {} to run meaningful tests.",
+            capacity
+        );
+
+        cache.validate().unwrap();
+
+        for i in 0..capacity {
+            assert_eq!(cache.insert(i, i).unwrap(), Eviction::None);
+        }
+
+        assert!(cache.pin(&0).unwrap());
+
+        cache.validate().unwrap();
+
+        let link = cache.block_refs.get(&0).copied().unwrap();
+
+        cache.blocks.get_mut(&link).unwrap().pinned = false;
+        assert_eq!(
+            cache.validate(),
+            Err(LRUCacheViolation::PinStateMismatch { link, pinned: true })
+        );
+        cache.blocks.get_mut(&link).unwrap().pinned = true;
+
+        let lru_list = mem::replace(&mut cache.lru_list, cache.blocks.new_list());
+        assert_eq!(
+            cache.validate(),
+            Err(LRUCacheViolation::ListViolation(
+                ListViolation::UnlinkedNodes {
+                    nodes: capacity,
+                    linked: 1
+                }
+            ))
+        );
+        cache.lru_list = lru_list;
+
+        let link = cache.block_refs.remove(&1).unwrap();
+        assert_eq!(
+            cache.validate(),
+            Err(LRUCacheViolation::BlockUnmapped { link })
+        );
+        cache.block_refs.insert(1, link).unwrap();
+
+        cache.block_refs.insert(capacity, link).unwrap();
+        assert_eq!(
+            cache.validate(),
+            Err(LRUCacheViolation::MapLenMismatch {
+                map_len: capacity + 1,
+                len: capacity
+            })
+        );
+        cache.block_refs.remove(&capacity).unwrap();
+
+        cache.capacity -= 1;
+        assert_eq!(
+            cache.validate(),
+            Err(LRUCacheViolation::CapacityExceeded {
+                len: capacity,
+                capacity: capacity - 1
+            })
+        );
+        cache.capacity += 1;
+
+        cache.validate().unwrap();
+
+        cache.shrink(capacity / 2).unwrap();
+        cache.compact();
+        cache.validate().unwrap();
+
+        cache.clear().unwrap();
+        cache.validate().unwrap();
+    }
 }
@@ -1,5 +1,7 @@
 //! Module providing abstractions to represent caches.
 
+use core::iter;
+
 /// The outcome of an eviction from a cache.
 ///
 /// Evictions occur in cache implementations on insert operations in a maxed out cache. This happens
@@ -26,6 +28,16 @@ pub enum Lookup<V> {
     Miss,
 }
 
+/// The outcome of a conditional insertion into a [`Cache`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Admission<K, V> {
+    /// The key/value pair was inserted, with the given eviction as the outcome.
+    Admitted(Eviction<K, V>),
+
+    /// The key/value pair was not inserted, and is handed back to the caller.
+    Rejected { key: K, value: V },
+}
+
 /// A size bounded map, where certain existing entries are evicted to make space for new entries.
 ///
 /// Implementations follow a well defined criteria to decide which cache blocks to evict in which
@@ -37,6 +49,19 @@ pub trait Cache<K, V> {
     /// Inserts the given key/value pair into this cache.
     fn insert(&mut self, key: K, value: V) -> Result<Eviction<K, V>, Self::Error>;
 
+    /// Inserts the given key/value pair into this cache, unless it would require evicting a
+    /// cache block.
+    ///
+    /// The value associated with an existing key is replaced as in [`Cache::insert`]. A pair
+    /// with a new key is rejected when this cache is maxed out.
+    fn try_insert(&mut self, key: K, value: V) -> Result<Admission<K, V>, Self::Error>;
+
+    /// Inserts the given key/value pair into this cache only if the key is not already present.
+    ///
+    /// An existing key is neither overwritten nor marked as used, and the pair is rejected.
+    /// Otherwise, the pair is inserted as in [`Cache::insert`], evicting a block if required.
+    fn insert_if_absent(&mut self, key: K, value: V) -> Result<Admission<K, V>, Self::Error>;
+
     /// Removes the key/value pair associated with the given key from this cache.
     fn remove(&mut self, key: &K) -> Result<Lookup<V>, Self::Error>;
 
@@ -47,6 +72,21 @@ pub trait Cache<K, V> {
     /// Reserves additional memory to accomodate the given number of additional cache blocks.
     fn reserve(&mut self, additional: usize) -> Result<(), Self::Error>;
 
+    /// Resizes this cache to the given capacity.
+    ///
+    /// Shrinking evicts cache blocks as in [`Cache::shrink`]. Implementations may additionally
+    /// release the memory held for the excess capacity. Growing reserves the additional capacity
+    /// as in [`Cache::reserve`].
+    fn resize(&mut self, new_capacity: usize) -> Result<(), Self::Error> {
+        let capacity = self.capacity();
+
+        if new_capacity < capacity {
+            self.shrink(new_capacity)
+        } else {
+            self.reserve(new_capacity - capacity)
+        }
+    }
+
     /// Queries this cache to find the value associated with given key.
     fn query(&mut self, key: &K) -> Result<Lookup<&V>, Self::Error>;
 
@@ -70,6 +110,59 @@ pub trait Cache<K, V> {
 
     /// Remove all items from this cache until it's empty.
     fn clear(&mut self) -> Result<(), Self::Error>;
+
+    /// Inserts the given key/value pairs into this cache in order, extending `evictions` with
+    /// the [`Eviction`] resulting from each insertion.
+    ///
+    /// Insertion stops at the first error. Evictions from the preceding insertions are retained
+    /// in `evictions`.
+    fn insert_many<I, E>(&mut self, pairs: I, evictions: &mut E) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        E: Extend<Eviction<K, V>>,
+    {
+        for (key, value) in pairs {
+            evictions.extend(iter::once(self.insert(key, value)?));
+        }
+
+        Ok(())
+    }
+
+    /// Queries this cache for each of the given keys in order, invoking `f` with every key and
+    /// its [`Lookup`].
+    ///
+    /// Since lookups borrow from this cache, they are handed to a callback instead of being
+    /// collected.
+    fn query_many<'a, I, F>(&mut self, keys: I, mut f: F) -> Result<(), Self::Error>
+    where
+        K: 'a,
+        I: IntoIterator<Item = &'a K>,
+        F: FnMut(&K, Lookup<&V>),
+    {
+        for key in keys {
+            f(key, self.query(key)?);
+        }
+
+        Ok(())
+    }
+
+    /// Removes the key/value pairs associated with the given keys from this cache, extending
+    /// `lookups` with the [`Lookup`] for every key in order.
+    ///
+    /// Removal stops at the first error. Lookups for the preceding keys are retained in
+    /// `lookups`.
+    fn remove_many<'a, I, E>(&mut self, keys: I, lookups: &mut E) -> Result<(), Self::Error>
+    where
+        K: 'a,
+        I: IntoIterator<Item = &'a K>,
+        E: Extend<Lookup<V>>,
+    {
+        for key in keys {
+            lookups.extend(iter::once(self.remove(key)?));
+        }
+
+        Ok(())
+    }
 }
 
 pub mod lru_cache;
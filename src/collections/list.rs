@@ -1,28 +1,40 @@
 //! Module providing abstractions for a linked list implementation.
 
-use core::fmt::{self, Debug, Display};
+use core::{
+    fmt::{self, Debug, Display},
+    marker::PhantomData,
+};
 
 use crate::{
-    arena::{Arena, ArenaError, Entry, Index},
+    arena::{
+        Arena, ArenaEntry, ArenaError, ArenaIndex, ArenaViolation, Index, IndexFor,
+        IterMut as ArenaIterMut,
+    },
     vector::Vector,
 };
 
 /// Represents a link to node in the linked list.
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub struct Link {
-    pub index: Index,
+///
+/// Links may only be used with the [`LinkedList`] or [`ListArena`] which created them. A link
+/// only stores the index of its node, so a link used with another list of the same element type
+/// is not detected: it refers to whichever node is stored at its index, or to no node if that
+/// entry is vacant or of another generation. Use a [`TypedIndex`](crate::arena::TypedIndex) to
+/// tell apart links to lists of different element types at compile time.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Link<I = Index> {
+    pub index: I,
 }
 
 /// Represents a node in a linked list.
 #[derive(Clone, Copy, PartialEq, Debug)]
-pub struct Node<T> {
+pub struct Node<T, I = Index> {
     pub value: T,
 
-    pub next: Option<Link>,
-    pub prev: Option<Link>,
+    pub next: Option<Link<I>>,
+    pub prev: Option<Link<I>>,
 }
 
-impl<T> Node<T> {
+impl<T, I> Node<T, I> {
     pub fn with_value(value: T) -> Self {
         Self {
             value,
@@ -32,7 +44,7 @@ impl<T> Node<T> {
     }
 }
 
-impl<T> Default for Node<T>
+impl<T, I> Default for Node<T, I>
 where
     T: Default,
 {
@@ -45,14 +57,60 @@ where
     }
 }
 
-/// A double-linked linked list implementation using a generational [`Arena`] for allocation.
-pub struct LinkedList<V, T> {
-    backing_arena: Arena<V, Node<T>>,
+/// A pool of linked list [`Node`]s allocated from a generational [`Arena`], shared by any
+/// number of [`SharedList`]s.
+///
+/// Since all lists created from the same arena share its nodes, a node may be moved from one
+/// list to another by relinking it in `O(1)`, without moving the element stored in it. The
+/// [`Link`]s to moved nodes remain valid.
+pub struct ListArena<V, T, I = Index>
+where
+    I: ArenaIndex,
+{
+    backing_arena: Arena<V, Node<T, I>, I>,
+}
 
-    head: Option<Link>,
-    tail: Option<Link>,
+/// A double-linked list whose nodes are allocated from a [`ListArena`].
+///
+/// A [`SharedList`] only stores the head, tail and length of the list. Every operation takes
+/// the [`ListArena`] the list was created from. Using a list with another arena is a logic
+/// error, which is not detected.
+///
+/// # Links to nodes of other lists
+///
+/// Nodes do not record the list they are linked in, so operations taking the [`Link`] to a
+/// node of this list cannot tell it apart from the [`Link`] to a node in the middle of another
+/// list sharing the same arena. Passing such a link is a logic error: the node is relinked,
+/// but the lengths of both lists no longer match their nodes, as reported by
+/// [`SharedList::validate`]. It never causes a panic or undefined behaviour, and links to the
+/// head or tail of another list, or to any node when this list holds fewer than three nodes,
+/// are rejected.
+pub struct SharedList<T, I = Index> {
+    head: Option<Link<I>>,
+    tail: Option<Link<I>>,
 
     len: usize,
+
+    _phantom_type: PhantomData<fn() -> T>,
+}
+
+/// A double-linked linked list implementation using a generational [`Arena`] for allocation.
+///
+/// # Mutable iteration
+///
+/// There is no iterator yielding mutable references to the elements in list order. Such an
+/// iterator hands out references to nodes reached through links, and proving those nodes
+/// distinct requires either `unsafe` code, which this crate does not use, or an allocation
+/// proportional to the capacity of the list, which fixed capacity lists are meant to avoid.
+/// Use [`LinkedList::for_each_mut`] or a [`CursorMut`] to mutate the elements in order, or
+/// [`LinkedList::values_mut_unordered`] to iterate over them in no particular order.
+pub struct LinkedList<V, T, I = Index>
+where
+    I: ArenaIndex,
+{
+    nodes: ListArena<V, T, I>,
+
+    header: SharedList<T, I>,
 }
 
 /// Error type associated with list operations.
@@ -77,35 +135,109 @@ where
     }
 }
 
+/// Inconsistency in the internal state of a [`LinkedList`], reported by
+/// [`LinkedList::validate`], [`SharedList::validate`] and [`ListArena::validate`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ListViolation<I = Index> {
+    /// Used when the underlying arena is inconsistent.
+    ArenaViolation(ArenaViolation),
+
+    /// Used when the given link does not refer to a node in the underlying arena.
+    LinkBroken { link: Link<I> },
+
+    /// Used when the previous link of the node at the given link does not refer to the node
+    /// preceding it.
+    PrevMismatch {
+        link: Link<I>,
+        prev: Option<Link<I>>,
+        expected: Option<Link<I>>,
+    },
+
+    /// Used when the node at the given link is reachable from the head beyond the length of
+    /// the list, either due to a cycle or an incorrect length.
+    LinkedBeyondLen { link: Link<I> },
+
+    /// Used when fewer nodes than the length of the list are reachable from the head.
+    LenMismatch { len: usize, linked: usize },
+
+    /// Used when the tail is not the last node reachable from the head.
+    TailMismatch {
+        tail: Option<Link<I>>,
+        last: Option<Link<I>>,
+    },
+
+    /// Used when the underlying arena holds nodes not reachable from the head.
+    UnlinkedNodes { nodes: usize, linked: usize },
+}
+
+impl<I> Display for ListViolation<I>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
 /// Type alias for arena entries corresponding to [`LinkedList`] [`Node`] instances.
-pub type LinkedListArenaEntry<T> = Entry<Node<T>>;
+pub type LinkedListArenaEntry<T, I = Index> = ArenaEntry<Node<T, I>, I>;
 
-impl<V, T> LinkedList<V, T>
+impl<V, T, I> ListArena<V, T, I>
 where
-    V: Vector<Entry<Node<T>>>,
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
 {
-    /// Creates a new [`LinkedList`] with given the backing [`Vector`] for the underlying [`Arena`].
-    pub fn with_backing_vector(vector: V) -> Self {
-        Self {
-            backing_arena: Arena::with_vector(vector),
+    /// Creates a new [`ListArena`] with the given backing [`Vector`] for the underlying [`Arena`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying [`Arena`] cannot be initialized. Use
+    /// [`ListArena::try_with_backing_vector`] for a non-panicking alternative.
+    pub fn with_backing_vector(vector: V) -> Self
+    where
+        I: IndexFor<Node<T, I>>,
+    {
+        Self::try_with_backing_vector(vector).unwrap()
+    }
+
+    /// Creates a new [`ListArena`] with the given backing [`Vector`] for the underlying [`Arena`].
+    ///
+    /// Returns an error if the underlying [`Arena`] cannot be initialized.
+    pub fn try_with_backing_vector(vector: V) -> Result<Self, ListError<V::Error>>
+    where
+        I: IndexFor<Node<T, I>>,
+    {
+        Ok(Self {
+            backing_arena: Arena::try_with_vector(vector).map_err(ListError::ArenaError)?,
+        })
+    }
+
+    /// Creates a new empty [`SharedList`] allocating its nodes from this arena.
+    pub fn new_list(&self) -> SharedList<T, I> {
+        SharedList {
             head: None,
             tail: None,
             len: 0,
+            _phantom_type: PhantomData,
         }
     }
 
-    /// Removes all elements from this [`LinkedList`].
-    pub fn clear(&mut self) -> Result<(), ListError<V::Error>> {
-        self.backing_arena.clear().map_err(ListError::ArenaError)?;
+    /// Returns the number of nodes this arena is capable of storing.
+    pub fn capacity(&self) -> usize {
+        self.backing_arena.capacity()
+    }
 
-        self.head = None;
-        self.tail = None;
-        self.len = 0;
+    /// Returns the number of nodes allocated in this arena, across all lists.
+    pub fn len(&self) -> usize {
+        self.backing_arena.len()
+    }
 
-        Ok(())
+    /// Returns whether no nodes are allocated in this arena.
+    pub fn is_empty(&self) -> bool {
+        self.backing_arena.is_empty()
     }
 
-    /// Reserves memory for the give number of additional elements in this [`LinkedList`].
+    /// Reserves memory for the given number of additional nodes in this arena.
     pub fn reserve(&mut self, additional: usize) -> Result<(), ListError<V::Error>> {
         let remaining = self.capacity() - self.len();
 
@@ -114,53 +246,202 @@ where
         }
 
         self.backing_arena
-            .reserve(additional)
+            .reserve(additional - remaining)
             .map_err(ListError::ArenaError)
     }
 
-    /// Returns the number of elements this [`LinkedList`] is capable of storing.
+    /// Shrinks the capacity of this arena to the given capacity, releasing memory in the
+    /// underlying [`Arena`]. The capacity is never reduced below the number of allocated nodes.
     ///
-    /// Since this [`LinkedList`] uses an [`Arena`] for allocation, it's capacity is subject to the
-    /// capacity of the underlying [`Arena`].
-    pub fn capacity(&self) -> usize {
-        self.backing_arena.capacity()
-    }
-
-    /// Returns the number of elements stored in this [`LinkedList`].
-    pub fn len(&self) -> usize {
-        self.len
-    }
-
-    /// Returns whether this [`LinkedList`] is empty.
-    pub fn is_empty(&self) -> bool {
-        self.head.is_none()
+    /// Nodes stored beyond the new capacity are moved within it, which changes their [`Link`].
+    /// The heads and tails of the given lists are updated accordingly, so `lists` must hold
+    /// every list with nodes in this arena. After every move, `relocated` is invoked with the
+    /// element stored in the moved node, and the old and new [`Link`] of the moved node.
+    pub fn shrink_to<F>(
+        &mut self,
+        new_capacity: usize,
+        lists: &mut [&mut SharedList<T, I>],
+        mut relocated: F,
+    ) where
+        F: FnMut(&T, Link<I>, Link<I>),
+    {
+        self.backing_arena
+            .shrink_to(new_capacity, |arena, old_index, new_index| {
+                let old_link = Link { index: old_index };
+                let new_link = Link { index: new_index };
+
+                let (prev, next) = match arena.get(&new_index) {
+                    Some(node) => (node.prev, node.next),
+                    None => return,
+                };
+
+                match prev.and_then(|prev| arena.get_mut(&prev.index)) {
+                    Some(prev_node) => prev_node.next = Some(new_link),
+                    None => {
+                        if let Some(list) =
+                            lists.iter_mut().find(|list| list.head == Some(old_link))
+                        {
+                            list.head = Some(new_link);
+                        }
+                    }
+                }
+
+                match next.and_then(|next| arena.get_mut(&next.index)) {
+                    Some(next_node) => next_node.prev = Some(new_link),
+                    None => {
+                        if let Some(list) =
+                            lists.iter_mut().find(|list| list.tail == Some(old_link))
+                        {
+                            list.tail = Some(new_link);
+                        }
+                    }
+                }
+
+                if let Some(node) = arena.get(&new_index) {
+                    relocated(&node.value, old_link, new_link);
+                }
+            });
     }
 
     /// Returns a mutable reference to the [`Node`] referenced by the given [`Link`].
-    fn get_node_mut(&mut self, link: &Link) -> Option<&mut Node<T>> {
+    fn get_node_mut(&mut self, link: &Link<I>) -> Option<&mut Node<T, I>> {
         self.backing_arena.get_mut(&link.index)
     }
 
     /// Returns an immutable reference to the [`Node`] referenced by the given [`Link`].
-    fn get_node(&self, link: &Link) -> Option<&Node<T>> {
+    fn get_node(&self, link: &Link<I>) -> Option<&Node<T, I>> {
         self.backing_arena.get(&link.index)
     }
 
+    /// Returns mutable references to the three distinct [`Node`]s at the given [`Link`]s.
+    fn get_nodes_mut(&mut self, links: [Link<I>; 3]) -> Option<[&mut Node<T, I>; 3]> {
+        self.backing_arena
+            .get_many_mut(&links.map(|link| link.index))
+            .ok()
+    }
+
     /// Returns a mutable reference to the element stored in the [`Node`] at the given [`Link`].
-    pub fn get_mut(&mut self, link: &Link) -> Option<&mut T> {
+    pub fn get_mut(&mut self, link: &Link<I>) -> Option<&mut T> {
         Some(&mut self.get_node_mut(link)?.value)
     }
 
     /// Returns an imutable reference to the element stored in the [`Node`] at the given [`Link`].
-    pub fn get(&self, link: &Link) -> Option<&T> {
+    pub fn get(&self, link: &Link<I>) -> Option<&T> {
         Some(&self.get_node(link)?.value)
     }
 
-    fn link_head(&mut self, link: Link) -> Option<()> {
-        self.get_node_mut(&link)?.next = self.head;
+    /// Returns the [`Link`] to the node following the node at the given [`Link`].
+    pub fn next_link(&self, link: &Link<I>) -> Option<&Link<I>> {
+        self.get_node(link)?.next.as_ref()
+    }
+
+    /// Returns the [`Link`] to the node preceding the node at the given [`Link`].
+    pub fn prev_link(&self, link: &Link<I>) -> Option<&Link<I>> {
+        self.get_node(link)?.prev.as_ref()
+    }
+
+    /// Allocates a detached node storing the given element in the underlying [`Arena`].
+    fn allocate(&mut self, value: T) -> Result<Link<I>, ListError<V::Error>> {
+        let node_index = self
+            .backing_arena
+            .insert(Node::with_value(value))
+            .map_err(ListError::ArenaError)?;
+
+        Ok(Link { index: node_index })
+    }
+
+    /// Reclaims the detached node at the given [`Link`] and returns its element.
+    fn reclaim(&mut self, link: &Link<I>) -> Option<T> {
+        let node = self.backing_arena.remove(&link.index)?;
+        Some(node.value)
+    }
+
+    /// Checks the internal consistency of the underlying [`Arena`]. Use
+    /// [`SharedList::validate`] to check the lists allocated from this arena.
+    pub fn validate(&self) -> Result<(), ListViolation<I>> {
+        self.backing_arena
+            .validate()
+            .map_err(ListViolation::ArenaViolation)
+    }
+}
+
+/// Dumps the internal state of a [`ListArena`], including its underlying [`Arena`].
+impl<V, T, I> Debug for ListArena<V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    T: Debug,
+    I: ArenaIndex,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListArena")
+            .field("backing_arena", &self.backing_arena)
+            .finish()
+    }
+}
+
+impl<V, T, I> Default for ListArena<V, T, I>
+where
+    V: Default + Vector<LinkedListArenaEntry<T, I>>,
+    I: IndexFor<Node<T, I>>,
+{
+    fn default() -> Self {
+        Self::with_backing_vector(V::default())
+    }
+}
+
+impl<T, I> SharedList<T, I>
+where
+    I: ArenaIndex,
+{
+    /// Returns the number of elements stored in this list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether this list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns the [`Link`] to the node at the front of this list.
+    pub fn peek_front_link(&self) -> Option<&Link<I>> {
+        self.head.as_ref()
+    }
+
+    /// Returns the [`Link`] to the node at the back of this list.
+    pub fn peek_back_link(&self) -> Option<&Link<I>> {
+        self.tail.as_ref()
+    }
+}
+
+impl<T, I> SharedList<T, I>
+where
+    I: ArenaIndex,
+{
+    /// Peeks the element at the front of this list.
+    pub fn peek_front<'a, V>(&self, arena: &'a ListArena<V, T, I>) -> Option<&'a T>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        arena.get(self.head.as_ref()?)
+    }
+
+    /// Peeks the element at the back of this list.
+    pub fn peek_back<'a, V>(&self, arena: &'a ListArena<V, T, I>) -> Option<&'a T>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        arena.get(self.tail.as_ref()?)
+    }
+
+    fn link_head<V>(&mut self, arena: &mut ListArena<V, T, I>, link: Link<I>) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        arena.get_node_mut(&link)?.next = self.head;
 
         if let Some(head_link) = self.head {
-            self.get_node_mut(&head_link)?.prev = Some(link);
+            arena.get_node_mut(&head_link)?.prev = Some(link);
         } else {
             self.tail = Some(link);
         }
@@ -172,11 +453,14 @@ where
         Some(())
     }
 
-    fn link_tail(&mut self, link: Link) -> Option<()> {
-        self.get_node_mut(&link)?.prev = self.tail;
+    fn link_tail<V>(&mut self, arena: &mut ListArena<V, T, I>, link: Link<I>) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        arena.get_node_mut(&link)?.prev = self.tail;
 
         if let Some(tail_link) = self.tail {
-            self.get_node_mut(&tail_link)?.next = Some(link);
+            arena.get_node_mut(&tail_link)?.next = Some(link);
         } else {
             self.head = Some(link);
         }
@@ -188,441 +472,3118 @@ where
         Some(())
     }
 
-    /// Pushes the given element to the front of this [`LinkedList`].
-    pub fn push_front(&mut self, value: T) -> Result<Link, ListError<V::Error>> {
-        let node_index = self
-            .backing_arena
-            .insert(Node::with_value(value))
-            .map_err(ListError::ArenaError)?;
+    /// Links the detached node at the given [`Link`] before the node at the `anchor` [`Link`].
+    fn link_before<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        anchor: Link<I>,
+        link: Link<I>,
+    ) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let prev_link = match arena.get_node(&anchor)?.prev {
+            Some(prev_link) => prev_link,
+            None => return self.link_head(arena, link),
+        };
 
-        let node_link = Link { index: node_index };
+        let [node, prev_node, anchor_node] = arena.get_nodes_mut([link, prev_link, anchor])?;
 
-        self.link_head(node_link).ok_or(ListError::LinkBroken)?;
+        node.prev = Some(prev_link);
+        node.next = Some(anchor);
 
-        Ok(node_link)
+        prev_node.next = Some(link);
+        anchor_node.prev = Some(link);
+
+        self.len += 1;
+
+        Some(())
     }
 
-    /// Pushes the given element to the back of this [`LinkedList`].
-    pub fn push_back(&mut self, value: T) -> Result<Link, ListError<V::Error>> {
-        let node_index = self
-            .backing_arena
-            .insert(Node::with_value(value))
-            .map_err(ListError::ArenaError)?;
+    /// Links the detached node at the given [`Link`] after the node at the `anchor` [`Link`].
+    fn link_after<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        anchor: Link<I>,
+        link: Link<I>,
+    ) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let next_link = match arena.get_node(&anchor)?.next {
+            Some(next_link) => next_link,
+            None => return self.link_tail(arena, link),
+        };
 
-        let node_link = Link { index: node_index };
+        let [node, anchor_node, next_node] = arena.get_nodes_mut([link, anchor, next_link])?;
 
-        self.link_tail(node_link).ok_or(ListError::LinkBroken)?;
+        node.prev = Some(anchor);
+        node.next = Some(next_link);
 
-        Ok(node_link)
-    }
+        anchor_node.next = Some(link);
+        next_node.prev = Some(link);
 
-    /// Peeks the element at the front of this list.
-    pub fn peek_front(&self) -> Option<&T> {
-        self.get(self.head.as_ref()?)
-    }
+        self.len += 1;
 
-    /// Peeks the element at the back of this list.
-    pub fn peek_back(&self) -> Option<&T> {
-        self.get(self.tail.as_ref()?)
+        Some(())
     }
 
-    fn unlink_head(&mut self) -> Option<Link> {
+    fn unlink_head<V>(&mut self, arena: &mut ListArena<V, T, I>) -> Option<Link<I>>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
         let head_link = self.head?;
-        self.head = self.get_node(&head_link)?.next;
+        self.head = arena.get_node_mut(&head_link)?.next.take();
 
         let to_unlink = match self.head {
-            Some(new_head_link) => &mut self.get_node_mut(&new_head_link)?.prev,
+            Some(new_head_link) => &mut arena.get_node_mut(&new_head_link)?.prev,
             None => &mut self.tail,
         };
 
         *to_unlink = None;
 
-        self.len -= 1;
+        self.len = self.len.saturating_sub(1);
 
         Some(head_link)
     }
 
-    fn unlink_tail(&mut self) -> Option<Link> {
+    fn unlink_tail<V>(&mut self, arena: &mut ListArena<V, T, I>) -> Option<Link<I>>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
         let tail_link = self.tail?;
-        self.tail = self.get_node(&tail_link)?.prev;
+        self.tail = arena.get_node_mut(&tail_link)?.prev.take();
 
         let to_unlink = match self.tail {
-            Some(new_tail_link) => &mut self.get_node_mut(&new_tail_link)?.next,
+            Some(new_tail_link) => &mut arena.get_node_mut(&new_tail_link)?.next,
             None => &mut self.head,
         };
 
         *to_unlink = None;
 
-        self.len -= 1;
+        self.len = self.len.saturating_sub(1);
 
         Some(tail_link)
     }
 
-    fn unlink(&mut self, link: &Link) -> Option<Link> {
+    fn unlink<V>(&mut self, arena: &mut ListArena<V, T, I>, link: &Link<I>) -> Option<Link<I>>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
         match Some(link) {
-            link if link == self.head.as_ref() => self.unlink_head(),
-            link if link == self.tail.as_ref() => self.unlink_tail(),
+            link if link == self.head.as_ref() => self.unlink_head(arena),
+            link if link == self.tail.as_ref() => self.unlink_tail(arena),
+            // a node with both a previous and a next node needs at least three nodes in the
+            // list, which rules out links to nodes of other lists when this list is short
+            _ if self.len < 3 => None,
             _ => {
-                let node = self.get_node_mut(link)?;
+                let node = arena.get_node(link)?;
 
                 let prev_link = node.prev?;
                 let next_link = node.next?;
 
+                let [node, prev_node, next_node] =
+                    arena.get_nodes_mut([*link, prev_link, next_link])?;
+
                 node.next = None;
                 node.prev = None;
 
-                self.get_node_mut(&prev_link)?.next = Some(next_link);
-                self.get_node_mut(&next_link)?.prev = Some(prev_link);
+                prev_node.next = Some(next_link);
+                next_node.prev = Some(prev_link);
 
-                self.len -= 1;
+                self.len = self.len.saturating_sub(1);
 
                 Some(*link)
             }
         }
     }
 
-    fn reclaim(&mut self, link: &Link) -> Option<T> {
-        let node = self.backing_arena.remove(&link.index)?;
-        Some(node.value)
-    }
-
-    /// Removes the element referenced by the given link.
-    pub fn remove(&mut self, link: &Link) -> Option<T> {
-        let link = self.unlink(link)?;
-        self.reclaim(&link)
-    }
-
-    /// Removes the element at the front of this list.
-    pub fn pop_front(&mut self) -> Option<T> {
-        let link = self.unlink_head()?;
-        self.reclaim(&link)
-    }
+    /// Pushes the given element to the front of this list.
+    pub fn push_front<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        value: T,
+    ) -> Result<Link<I>, ListError<V::Error>>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let node_link = arena.allocate(value)?;
 
-    /// Removes the element at the back of this list.
-    pub fn pop_back(&mut self) -> Option<T> {
-        let link = self.unlink_tail()?;
-        self.reclaim(&link)
-    }
+        self.link_head(arena, node_link)
+            .ok_or(ListError::LinkBroken)?;
 
-    /// Shifts the element at the given [`Link`] to the front of this list.
-    pub fn shift_push_front(&mut self, link: &Link) -> Option<()> {
-        let link = self.unlink(link)?;
-        self.link_head(link)
+        Ok(node_link)
     }
 
-    /// Shifts the element at the given [`Link`] to the back of this list.
-    pub fn shift_push_back(&mut self, link: &Link) -> Option<()> {
-        let link = self.unlink(link)?;
-        self.link_tail(link)
-    }
+    /// Pushes the given element to the back of this list.
+    pub fn push_back<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        value: T,
+    ) -> Result<Link<I>, ListError<V::Error>>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let node_link = arena.allocate(value)?;
 
-    /// Returns an iterator to iterate over the elements in this list.
-    pub fn iter(&self) -> Iter<'_, V, T> {
-        Iter {
-            list: self,
-            cursor: self.head.as_ref(),
-        }
-    }
-}
+        self.link_tail(arena, node_link)
+            .ok_or(ListError::LinkBroken)?;
 
-impl<V, T> Default for LinkedList<V, T>
-where
-    V: Default + Vector<Entry<Node<T>>>,
-{
-    fn default() -> Self {
-        Self::with_backing_vector(V::default())
+        Ok(node_link)
     }
-}
-
-/// Iterator implementation to iterate over the items in a [`LinkedList`].
-pub struct Iter<'a, V, T> {
-    list: &'a LinkedList<V, T>,
-    cursor: Option<&'a Link>,
-}
 
-impl<'a, V, T> Iterator for Iter<'a, V, T>
-where
-    V: Vector<Entry<Node<T>>>,
-{
-    type Item = (&'a Link, &'a T);
+    /// Inserts the given element before the node at the given [`Link`], and returns the
+    /// [`Link`] to its node.
+    ///
+    /// Returns [`ListError::LinkBroken`] if the given link does not refer to a node in the
+    /// given arena.
+    pub fn insert_before<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        link: &Link<I>,
+        value: T,
+    ) -> Result<Link<I>, ListError<V::Error>>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        arena.get_node(link).ok_or(ListError::LinkBroken)?;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let cursor = self.cursor.take()?;
-        let cursor_node = self.list.get_node(cursor)?;
+        let node_link = arena.allocate(value)?;
 
-        self.cursor = cursor_node.next.as_ref();
+        self.link_before(arena, *link, node_link)
+            .ok_or(ListError::LinkBroken)?;
 
-        Some((cursor, &cursor_node.value))
+        Ok(node_link)
     }
-}
 
-impl<'a, V, T> IntoIterator for &'a LinkedList<V, T>
-where
-    V: Vector<Entry<Node<T>>>,
-{
-    type Item = (&'a Link, &'a T);
+    /// Inserts the given element after the node at the given [`Link`], and returns the
+    /// [`Link`] to its node.
+    ///
+    /// Returns [`ListError::LinkBroken`] if the given link does not refer to a node in the
+    /// given arena.
+    pub fn insert_after<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        link: &Link<I>,
+        value: T,
+    ) -> Result<Link<I>, ListError<V::Error>>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        arena.get_node(link).ok_or(ListError::LinkBroken)?;
 
-    type IntoIter = Iter<'a, V, T>;
+        let node_link = arena.allocate(value)?;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
-    }
-}
+        self.link_after(arena, *link, node_link)
+            .ok_or(ListError::LinkBroken)?;
 
-#[doc(hidden)]
-pub mod tests {
-    use super::{
-        super::super::{
-            arena::{ArenaError, Entry},
-            collections::list::ListError,
-            vector::Vector,
-        },
-        LinkedList, Node,
-    };
-    use core::fmt::Debug;
+        Ok(node_link)
+    }
 
-    pub fn _test_list_invariants<T, V>(mut list: LinkedList<V, T>)
+    /// Removes the element referenced by the given link.
+    ///
+    /// The link must refer to a node in this list, see [links to nodes of other
+    /// lists](SharedList#links-to-nodes-of-other-lists).
+    pub fn remove<V>(&mut self, arena: &mut ListArena<V, T, I>, link: &Link<I>) -> Option<T>
     where
-        V: Vector<Entry<Node<T>>>,
-        T: Debug + PartialEq + Default,
+        V: Vector<LinkedListArenaEntry<T, I>>,
     {
-        list.clear().unwrap();
+        let link = self.unlink(arena, link)?;
+        arena.reclaim(&link)
+    }
 
-        let capacity = list.capacity();
+    /// Replaces the element at the given [`Link`] in place, without reclaiming its node.
+    /// Returns the new [`Link`] to the node along with the replaced element.
+    ///
+    /// As when removing the element and inserting the given one, the given link becomes stale.
+    /// Returns `None`, leaving this list unchanged, if the link does not refer to a node in the
+    /// given arena. The link must refer to a node in this list, see [links to nodes of other
+    /// lists](SharedList#links-to-nodes-of-other-lists).
+    pub fn replace<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        link: &Link<I>,
+        value: T,
+    ) -> Option<(Link<I>, T)>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let (prev, next) = match arena.get_node(link)? {
+            node if node.prev.is_none() && self.head != Some(*link) => return None,
+            node if node.next.is_none() && self.tail != Some(*link) => return None,
+            node => (node.prev, node.next),
+        };
 
-        assert!(list.is_empty());
+        let (index, node) = arena
+            .backing_arena
+            .replace(&link.index, Node { value, next, prev })?;
 
-        assert_eq!(list.peek_front(), None);
-        assert_eq!(list.peek_back(), None);
+        let new_link = Link { index };
 
-        for _ in 0..capacity {
-            list.push_back(T::default()).unwrap();
+        match prev.and_then(|prev| arena.get_node_mut(&prev)) {
+            Some(prev_node) => prev_node.next = Some(new_link),
+            None => self.head = Some(new_link),
         }
 
-        assert!(list.len() == list.capacity());
-
-        let mut i = 0;
-        for (_, t) in &list {
-            assert_eq!(t, &T::default());
-            i += 1;
+        match next.and_then(|next| arena.get_node_mut(&next)) {
+            Some(next_node) => next_node.prev = Some(new_link),
+            None => self.tail = Some(new_link),
         }
 
-        assert_eq!(i, list.len());
-
-        assert_eq!(list.peek_front(), Some(&T::default()));
-        assert_eq!(list.peek_back(), Some(&T::default()));
+        Some((new_link, node.value))
+    }
+
+    /// Removes the element at the front of this list.
+    pub fn pop_front<V>(&mut self, arena: &mut ListArena<V, T, I>) -> Option<T>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let link = self.unlink_head(arena)?;
+        arena.reclaim(&link)
+    }
+
+    /// Removes the element at the back of this list.
+    pub fn pop_back<V>(&mut self, arena: &mut ListArena<V, T, I>) -> Option<T>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let link = self.unlink_tail(arena)?;
+        arena.reclaim(&link)
+    }
+
+    /// Removes all elements from this list, reclaiming their nodes in the given arena.
+    ///
+    /// This operation is `O(n)` in the length of this list.
+    pub fn clear<V>(&mut self, arena: &mut ListArena<V, T, I>)
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        while self.pop_front(arena).is_some() {}
+    }
+
+    /// Shifts the element at the given [`Link`] to the front of this list.
+    ///
+    /// The link must refer to a node in this list, see [links to nodes of other
+    /// lists](SharedList#links-to-nodes-of-other-lists).
+    pub fn shift_push_front<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        link: &Link<I>,
+    ) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let link = self.unlink(arena, link)?;
+        self.link_head(arena, link)
+    }
+
+    /// Shifts the element at the given [`Link`] to the back of this list.
+    ///
+    /// The link must refer to a node in this list, see [links to nodes of other
+    /// lists](SharedList#links-to-nodes-of-other-lists).
+    pub fn shift_push_back<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        link: &Link<I>,
+    ) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let link = self.unlink(arena, link)?;
+        self.link_tail(arena, link)
+    }
+
+    /// Moves the node at the given [`Link`] before the node at the `anchor` [`Link`]. The
+    /// [`Link`] to the moved node remains valid.
+    ///
+    /// Returns `None`, leaving this list unchanged, if either link does not refer to a node in
+    /// the given arena. Moving a node before itself has no effect. Both links must refer to nodes
+    /// in this list, see [links to nodes of other lists](SharedList#links-to-nodes-of-other-lists).
+    pub fn move_before<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        link: &Link<I>,
+        anchor: &Link<I>,
+    ) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        arena.get_node(anchor)?;
+
+        if link == anchor {
+            return Some(());
+        }
+
+        let link = self.unlink(arena, link)?;
+        self.link_before(arena, *anchor, link)
+    }
+
+    /// Moves the node at the given [`Link`] after the node at the `anchor` [`Link`]. The
+    /// [`Link`] to the moved node remains valid.
+    ///
+    /// Returns `None`, leaving this list unchanged, if either link does not refer to a node in
+    /// the given arena. Moving a node after itself has no effect. Both links must refer to nodes
+    /// in this list, see [links to nodes of other lists](SharedList#links-to-nodes-of-other-lists).
+    pub fn move_after<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        link: &Link<I>,
+        anchor: &Link<I>,
+    ) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        arena.get_node(anchor)?;
+
+        if link == anchor {
+            return Some(());
+        }
+
+        let link = self.unlink(arena, link)?;
+        self.link_after(arena, *anchor, link)
+    }
+
+    /// Moves the node at the given [`Link`] from this list to the front of the `other` list
+    /// sharing the given arena, in `O(1)`. The [`Link`] to the moved node remains valid.
+    ///
+    /// Returns `None`, leaving both lists unchanged, if the link does not refer to a node in
+    /// the given arena. The link must refer to a node in this list, see [links to nodes of
+    /// other lists](SharedList#links-to-nodes-of-other-lists).
+    pub fn transfer_to_front<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        link: &Link<I>,
+        other: &mut Self,
+    ) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let link = self.unlink(arena, link)?;
+        other.link_head(arena, link)
+    }
+
+    /// Moves the node at the given [`Link`] from this list to the back of the `other` list
+    /// sharing the given arena, in `O(1)`. The [`Link`] to the moved node remains valid.
+    ///
+    /// Returns `None`, leaving both lists unchanged, if the link does not refer to a node in
+    /// the given arena. The link must refer to a node in this list, see [links to nodes of
+    /// other lists](SharedList#links-to-nodes-of-other-lists).
+    pub fn transfer_to_back<V>(
+        &mut self,
+        arena: &mut ListArena<V, T, I>,
+        link: &Link<I>,
+        other: &mut Self,
+    ) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let link = self.unlink(arena, link)?;
+        other.link_tail(arena, link)
+    }
+
+    /// Moves all elements from the `other` list sharing the given arena to the back of this
+    /// list, in `O(1)`. The [`Link`]s to the moved nodes remain valid.
+    pub fn append<V>(&mut self, arena: &mut ListArena<V, T, I>, other: &mut Self) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let (other_head, other_tail) = match (other.head, other.tail) {
+            (Some(other_head), Some(other_tail)) => (other_head, other_tail),
+            _ => return Some(()),
+        };
+
+        match self.tail {
+            Some(tail) => {
+                arena.get_node_mut(&tail)?.next = Some(other_head);
+                arena.get_node_mut(&other_head)?.prev = Some(tail);
+            }
+            None => self.head = Some(other_head),
+        }
+
+        self.tail = Some(other_tail);
+        self.len += other.len;
+
+        other.head = None;
+        other.tail = None;
+        other.len = 0;
+
+        Some(())
+    }
+
+    /// Splits this list in two at the node at the given [`Link`]. Returns a new list sharing
+    /// the given arena, containing the node at the given link and all nodes following it. The
+    /// [`Link`]s to the moved nodes remain valid.
+    ///
+    /// Returns `None`, leaving this list unchanged, if the link does not refer to a node in
+    /// this list. This operation is `O(k)` in the number of nodes split off.
+    pub fn split_off<V>(&mut self, arena: &mut ListArena<V, T, I>, link: &Link<I>) -> Option<Self>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let mut split_len = 1;
+        let mut cursor = *link;
+
+        while let Some(next) = arena.next_link(&cursor) {
+            cursor = *next;
+            split_len += 1;
+        }
+
+        if Some(cursor) != self.tail || split_len > self.len {
+            return None;
+        }
+
+        let mut other = arena.new_list();
+
+        other.head = Some(*link);
+        other.tail = self.tail;
+        other.len = split_len;
+
+        match arena.get_node_mut(link)?.prev.take() {
+            Some(prev) => {
+                arena.get_node_mut(&prev)?.next = None;
+                self.tail = Some(prev);
+            }
+            None => {
+                self.head = None;
+                self.tail = None;
+            }
+        }
+
+        self.len -= split_len;
+
+        Some(other)
+    }
+
+    /// Sorts this list with the given comparator function, preserving the order of equal
+    /// elements.
+    ///
+    /// The nodes are sorted in place with a bottom-up merge sort, by relinking them. No
+    /// elements are moved, and the [`Link`]s to all nodes remain valid. This operation is
+    /// `O(n log n)` in the length of this list and does not allocate.
+    pub fn sort_by<V, F>(&mut self, arena: &mut ListArena<V, T, I>, cmp: F)
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+        F: FnMut(&T, &T) -> core::cmp::Ordering,
+    {
+        let sorted = self.merge_sort_by(arena, cmp);
+
+        debug_assert!(sorted.is_some(), "Broken link encountered while sorting.");
+    }
+
+    /// Sorts this list with the given key extraction function, preserving the order of
+    /// elements with equal keys.
+    ///
+    /// See [`SharedList::sort_by`].
+    pub fn sort_by_key<V, K, F>(&mut self, arena: &mut ListArena<V, T, I>, mut f: F)
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(arena, |a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Merges sorted runs of doubling length, following only the next links of the nodes,
+    /// and restores the previous links once the nodes are in order.
+    fn merge_sort_by<V, F>(&mut self, arena: &mut ListArena<V, T, I>, mut cmp: F) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+        F: FnMut(&T, &T) -> core::cmp::Ordering,
+    {
+        if self.len < 2 {
+            return Some(());
+        }
+
+        let mut head = self.head;
+        let mut run_len = 1;
+
+        loop {
+            let mut left = head;
+            let mut merged_tail: Option<Link<I>> = None;
+            let mut merges = 0;
+
+            head = None;
+
+            while let Some(left_start) = left {
+                merges += 1;
+
+                let mut right = Some(left_start);
+                let mut left_len = 0;
+
+                while let Some(link) = right.filter(|_| left_len < run_len) {
+                    right = arena.next_link(&link).copied();
+                    left_len += 1;
+                }
+
+                let mut left_cur = Some(left_start);
+                let mut right_len = run_len;
+
+                loop {
+                    let take_left = match (
+                        left_cur.filter(|_| left_len > 0),
+                        right.filter(|_| right_len > 0),
+                    ) {
+                        (None, None) => break,
+                        (Some(_), None) => true,
+                        (None, Some(_)) => false,
+                        (Some(left_link), Some(right_link)) => cmp(
+                            &arena.get_node(&left_link)?.value,
+                            &arena.get_node(&right_link)?.value,
+                        )
+                        .is_le(),
+                    };
+
+                    let (cur, cur_len) = if take_left {
+                        (&mut left_cur, &mut left_len)
+                    } else {
+                        (&mut right, &mut right_len)
+                    };
+
+                    let link = (*cur)?;
+
+                    *cur = arena.next_link(&link).copied();
+                    *cur_len -= 1;
+
+                    match merged_tail {
+                        Some(tail_link) => arena.get_node_mut(&tail_link)?.next = Some(link),
+                        None => head = Some(link),
+                    }
+
+                    merged_tail = Some(link);
+                }
+
+                left = right;
+            }
+
+            arena.get_node_mut(&merged_tail?)?.next = None;
+
+            if merges <= 1 {
+                break;
+            }
+
+            run_len *= 2;
+        }
+
+        let mut prev = None;
+        let mut cursor = head;
+
+        while let Some(link) = cursor {
+            let node = arena.get_node_mut(&link)?;
+
+            node.prev = prev;
+            prev = Some(link);
+            cursor = node.next;
+        }
+
+        self.head = head;
+        self.tail = prev;
+
+        Some(())
+    }
+
+    /// Retains only the elements for which the given predicate returns `true`, visiting the
+    /// elements from front to back. The nodes of the removed elements are reclaimed in the
+    /// given arena, and the [`Link`]s to the retained elements remain valid.
+    pub fn retain<V, F>(&mut self, arena: &mut ListArena<V, T, I>, mut f: F)
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+        F: FnMut(&T) -> bool,
+    {
+        let mut cursor = self.head;
+
+        while let Some(link) = cursor {
+            let node = match arena.get_node(&link) {
+                Some(node) => node,
+                None => break,
+            };
+
+            cursor = node.next;
+
+            if !f(&node.value) {
+                self.remove(arena, &link);
+            }
+        }
+    }
+
+    /// Returns the [`Link`] to the first node, from the front, whose element satisfies the
+    /// given predicate.
+    pub fn find<V, F>(&self, arena: &ListArena<V, T, I>, mut f: F) -> Option<Link<I>>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+        F: FnMut(&T) -> bool,
+    {
+        self.iter_links(arena)
+            .find(|(_, value)| f(value))
+            .map(|(link, _)| link)
+    }
+
+    /// Returns the position, from the front, of the first element satisfying the given
+    /// predicate.
+    pub fn position<V, F>(&self, arena: &ListArena<V, T, I>, mut f: F) -> Option<usize>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+        F: FnMut(&T) -> bool,
+    {
+        self.iter(arena).position(|(_, value)| f(value))
+    }
+
+    /// Returns the [`Link`] to the node at the given position from the front of this list.
+    ///
+    /// The list is walked from whichever end is closer to the given position, so this
+    /// operation is `O(min(n, len - n))`.
+    pub fn nth_link<V>(&self, arena: &ListArena<V, T, I>, n: usize) -> Option<Link<I>>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        if n >= self.len {
+            return None;
+        }
+
+        let link = if n < self.len / 2 {
+            self.iter_links(arena).nth(n)
+        } else {
+            self.iter_links(arena).nth_back(self.len - n - 1)
+        };
+
+        link.map(|(link, _)| link)
+    }
+
+    /// Rotates this list `n` places to the left, moving the first `n` elements to the back,
+    /// in order. The [`Link`]s to all nodes remain valid.
+    ///
+    /// Returns `None`, leaving this list unchanged, if `n` is greater than the length of this
+    /// list. This operation is `O(min(n, len - n))`.
+    pub fn rotate_left<V>(&mut self, arena: &mut ListArena<V, T, I>, n: usize) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        if n > self.len {
+            return None;
+        }
+
+        if n == 0 || n == self.len {
+            return Some(());
+        }
+
+        let (head, tail) = (self.head?, self.tail?);
+
+        let new_head = self.nth_link(arena, n)?;
+        let new_tail = *arena.prev_link(&new_head)?;
+
+        arena.get_node_mut(&tail)?.next = Some(head);
+        arena.get_node_mut(&head)?.prev = Some(tail);
+
+        arena.get_node_mut(&new_head)?.prev = None;
+        arena.get_node_mut(&new_tail)?.next = None;
+
+        self.head = Some(new_head);
+        self.tail = Some(new_tail);
+
+        Some(())
+    }
+
+    /// Rotates this list `n` places to the right, moving the last `n` elements to the front,
+    /// in order. The [`Link`]s to all nodes remain valid.
+    ///
+    /// Returns `None`, leaving this list unchanged, if `n` is greater than the length of this
+    /// list. This operation is `O(min(n, len - n))`.
+    pub fn rotate_right<V>(&mut self, arena: &mut ListArena<V, T, I>, n: usize) -> Option<()>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let n = self.len.checked_sub(n)?;
+        self.rotate_left(arena, n)
+    }
+
+    /// Returns an iterator to iterate over the elements in this list, from front to back,
+    /// along with references to the [`Link`]s to their nodes.
+    pub fn iter<'a, V>(&'a self, arena: &'a ListArena<V, T, I>) -> Iter<'a, V, T, I>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        Iter {
+            nodes: arena,
+            front: self.head.as_ref(),
+            back: self.tail.as_ref(),
+            remaining: self.len,
+        }
+    }
+
+    /// Returns an iterator to iterate over the elements in this list, from front to back,
+    /// along with the [`Link`]s to their nodes.
+    pub fn iter_links<'a, V>(&'a self, arena: &'a ListArena<V, T, I>) -> IterLinks<'a, V, T, I>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        IterLinks {
+            iter: self.iter(arena),
+        }
+    }
+
+    /// Calls the given closure with a mutable reference to every element in this list, from
+    /// front to back.
+    pub fn for_each_mut<V, F>(&self, arena: &mut ListArena<V, T, I>, mut f: F)
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+        F: FnMut(&mut T),
+    {
+        let mut cursor = self.head;
+
+        for _ in 0..self.len {
+            let node = match cursor.and_then(|link| arena.get_node_mut(&link)) {
+                Some(node) => node,
+                None => break,
+            };
+
+            f(&mut node.value);
+
+            cursor = node.next;
+        }
+    }
+
+    /// Checks the consistency of this list with the nodes in the given arena.
+    ///
+    /// Walks the nodes from the head, verifying that every link belongs to the given arena,
+    /// that the previous and next links of adjacent nodes are symmetric, and that the length
+    /// and tail agree with the nodes reached. Returns the first [`ListViolation`] found.
+    ///
+    /// This operation is `O(n)` in the length of this list. The arena itself is checked with
+    /// [`ListArena::validate`].
+    pub fn validate<V>(&self, arena: &ListArena<V, T, I>) -> Result<(), ListViolation<I>>
+    where
+        V: Vector<LinkedListArenaEntry<T, I>>,
+    {
+        let mut linked = 0;
+        let mut last = None;
+        let mut cursor = self.head;
+
+        while let Some(link) = cursor {
+            let node = arena
+                .backing_arena
+                .get(&link.index)
+                .ok_or(ListViolation::LinkBroken { link })?;
+
+            if node.prev != last {
+                return Err(ListViolation::PrevMismatch {
+                    link,
+                    prev: node.prev,
+                    expected: last,
+                });
+            }
+
+            if linked == self.len {
+                return Err(ListViolation::LinkedBeyondLen { link });
+            }
+
+            linked += 1;
+            last = Some(link);
+            cursor = node.next;
+        }
+
+        if linked != self.len {
+            return Err(ListViolation::LenMismatch {
+                len: self.len,
+                linked,
+            });
+        }
+
+        if self.tail != last {
+            return Err(ListViolation::TailMismatch {
+                tail: self.tail,
+                last,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, I> Debug for SharedList<T, I>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedList")
+            .field("head", &self.head)
+            .field("tail", &self.tail)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<V, T, I> LinkedList<V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    /// Creates a new [`LinkedList`] with given the backing [`Vector`] for the underlying [`Arena`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying [`Arena`] cannot be initialized. Use
+    /// [`LinkedList::try_with_backing_vector`] for a non-panicking alternative.
+    pub fn with_backing_vector(vector: V) -> Self
+    where
+        I: IndexFor<Node<T, I>>,
+    {
+        Self::try_with_backing_vector(vector).unwrap()
+    }
+
+    /// Creates a new [`LinkedList`] with given the backing [`Vector`] for the underlying [`Arena`].
+    ///
+    /// Returns an error if the underlying [`Arena`] cannot be initialized.
+    pub fn try_with_backing_vector(vector: V) -> Result<Self, ListError<V::Error>>
+    where
+        I: IndexFor<Node<T, I>>,
+    {
+        let nodes = ListArena::try_with_backing_vector(vector)?;
+        let header = nodes.new_list();
+
+        Ok(Self { nodes, header })
+    }
+
+    /// Removes all elements from this [`LinkedList`].
+    pub fn clear(&mut self) -> Result<(), ListError<V::Error>> {
+        self.nodes
+            .backing_arena
+            .clear()
+            .map_err(ListError::ArenaError)?;
+
+        self.header = self.nodes.new_list();
+
+        Ok(())
+    }
+
+    /// Reserves memory for the give number of additional elements in this [`LinkedList`].
+    pub fn reserve(&mut self, additional: usize) -> Result<(), ListError<V::Error>> {
+        self.nodes.reserve(additional)
+    }
+
+    /// Pushes the given elements to the back of this [`LinkedList`], doubling its capacity
+    /// whenever it is full.
+    ///
+    /// Insertion stops at the first error. Elements pushed before the error remain in this list.
+    pub fn try_extend<It>(&mut self, iter: It) -> Result<(), ListError<V::Error>>
+    where
+        It: IntoIterator<Item = T>,
+    {
+        for value in iter {
+            if self.len() == self.capacity() {
+                self.reserve(self.capacity().max(1))?;
+            }
+
+            self.push_back(value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Shrinks the capacity of this [`LinkedList`] to the given capacity, releasing memory in
+    /// the underlying [`Arena`]. The capacity is never reduced below the length of this list.
+    ///
+    /// Nodes stored beyond the new capacity are moved within it, which changes their [`Link`].
+    /// After every move, `relocated` is invoked with the element stored in the moved node, and
+    /// the old and new [`Link`] of the moved node.
+    pub fn shrink_to<F>(&mut self, new_capacity: usize, relocated: F)
+    where
+        F: FnMut(&T, Link<I>, Link<I>),
+    {
+        let Self { nodes, header } = self;
+
+        nodes.shrink_to(new_capacity, &mut [header], relocated);
+    }
+
+    /// Moves all nodes to the front of the underlying [`Arena`] and releases the memory for
+    /// the free entries following them. The capacity of this list is reduced to its length.
+    ///
+    /// After every move, `remap` is invoked with the element stored in the moved node, and the
+    /// old and new [`Link`] of the moved node.
+    pub fn compact<F>(&mut self, remap: F)
+    where
+        F: FnMut(&T, Link<I>, Link<I>),
+    {
+        self.shrink_to(0, remap);
+    }
+
+    /// Returns the number of elements this [`LinkedList`] is capable of storing.
+    ///
+    /// Since this [`LinkedList`] uses an [`Arena`] for allocation, it's capacity is subject to the
+    /// capacity of the underlying [`Arena`].
+    pub fn capacity(&self) -> usize {
+        self.nodes.capacity()
+    }
+
+    /// Returns the number of elements stored in this [`LinkedList`].
+    pub fn len(&self) -> usize {
+        self.header.len()
+    }
+
+    /// Returns whether this [`LinkedList`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.header.is_empty()
+    }
+
+    /// Returns a mutable reference to the element stored in the [`Node`] at the given [`Link`].
+    pub fn get_mut(&mut self, link: &Link<I>) -> Option<&mut T> {
+        self.nodes.get_mut(link)
+    }
+
+    /// Returns an imutable reference to the element stored in the [`Node`] at the given [`Link`].
+    pub fn get(&self, link: &Link<I>) -> Option<&T> {
+        self.nodes.get(link)
+    }
+
+    /// Pushes the given element to the front of this [`LinkedList`].
+    pub fn push_front(&mut self, value: T) -> Result<Link<I>, ListError<V::Error>> {
+        self.header.push_front(&mut self.nodes, value)
+    }
+
+    /// Pushes the given element to the back of this [`LinkedList`].
+    pub fn push_back(&mut self, value: T) -> Result<Link<I>, ListError<V::Error>> {
+        self.header.push_back(&mut self.nodes, value)
+    }
+
+    /// Inserts the given element before the node at the given [`Link`], and returns the
+    /// [`Link`] to its node.
+    ///
+    /// Returns [`ListError::LinkBroken`] if the given link does not refer to a node in this list.
+    pub fn insert_before(
+        &mut self,
+        link: &Link<I>,
+        value: T,
+    ) -> Result<Link<I>, ListError<V::Error>> {
+        self.header.insert_before(&mut self.nodes, link, value)
+    }
+
+    /// Inserts the given element after the node at the given [`Link`], and returns the
+    /// [`Link`] to its node.
+    ///
+    /// Returns [`ListError::LinkBroken`] if the given link does not refer to a node in this list.
+    pub fn insert_after(
+        &mut self,
+        link: &Link<I>,
+        value: T,
+    ) -> Result<Link<I>, ListError<V::Error>> {
+        self.header.insert_after(&mut self.nodes, link, value)
+    }
+
+    /// Peeks the element at the front of this list.
+    pub fn peek_front(&self) -> Option<&T> {
+        self.header.peek_front(&self.nodes)
+    }
+
+    /// Peeks the element at the back of this list.
+    pub fn peek_back(&self) -> Option<&T> {
+        self.header.peek_back(&self.nodes)
+    }
+
+    /// Returns the [`Link`] to the node at the front of this list.
+    pub fn peek_front_link(&self) -> Option<&Link<I>> {
+        self.header.peek_front_link()
+    }
+
+    /// Returns the [`Link`] to the node at the back of this list.
+    pub fn peek_back_link(&self) -> Option<&Link<I>> {
+        self.header.peek_back_link()
+    }
+
+    /// Returns the [`Link`] to the node following the node at the given [`Link`].
+    pub fn next_link(&self, link: &Link<I>) -> Option<&Link<I>> {
+        self.nodes.next_link(link)
+    }
+
+    /// Returns the [`Link`] to the node preceding the node at the given [`Link`].
+    pub fn prev_link(&self, link: &Link<I>) -> Option<&Link<I>> {
+        self.nodes.prev_link(link)
+    }
+
+    /// Removes the element referenced by the given link.
+    pub fn remove(&mut self, link: &Link<I>) -> Option<T> {
+        self.header.remove(&mut self.nodes, link)
+    }
+
+    /// Removes the element at the front of this list.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.header.pop_front(&mut self.nodes)
+    }
+
+    /// Removes the element at the back of this list.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.header.pop_back(&mut self.nodes)
+    }
+
+    /// Shifts the element at the given [`Link`] to the front of this list.
+    pub fn shift_push_front(&mut self, link: &Link<I>) -> Option<()> {
+        self.header.shift_push_front(&mut self.nodes, link)
+    }
+
+    /// Shifts the element at the given [`Link`] to the back of this list.
+    pub fn shift_push_back(&mut self, link: &Link<I>) -> Option<()> {
+        self.header.shift_push_back(&mut self.nodes, link)
+    }
+
+    /// Moves the node at the given [`Link`] before the node at the `anchor` [`Link`]. The
+    /// [`Link`] to the moved node remains valid.
+    ///
+    /// Returns `None`, leaving this list unchanged, if either link does not refer to a node in
+    /// this list. Moving a node before itself has no effect.
+    pub fn move_before(&mut self, link: &Link<I>, anchor: &Link<I>) -> Option<()> {
+        self.header.move_before(&mut self.nodes, link, anchor)
+    }
+
+    /// Moves the node at the given [`Link`] after the node at the `anchor` [`Link`]. The
+    /// [`Link`] to the moved node remains valid.
+    ///
+    /// Returns `None`, leaving this list unchanged, if either link does not refer to a node in
+    /// this list. Moving a node after itself has no effect.
+    pub fn move_after(&mut self, link: &Link<I>, anchor: &Link<I>) -> Option<()> {
+        self.header.move_after(&mut self.nodes, link, anchor)
+    }
+
+    /// Moves the elements of the `other` list, in order, to the back of this list, one by one.
+    ///
+    /// This does not relink nodes: every element is popped from `other` and pushed into this
+    /// list, since each [`LinkedList`] owns its [`Arena`]. The operation is `O(n)` in the length
+    /// of `other`, needs capacity for all of its elements in this list, and invalidates every
+    /// [`Link`] to the moved elements. To append in `O(1)`, keeping the links valid, allocate
+    /// both lists from one [`ListArena`] and use [`SharedList::append`].
+    ///
+    /// Memory for the moved elements is reserved upfront. If it cannot be reserved, an error
+    /// is returned and both lists are left unchanged.
+    pub fn append_values<W, J>(
+        &mut self,
+        other: &mut LinkedList<W, T, J>,
+    ) -> Result<(), ListError<V::Error>>
+    where
+        W: Vector<LinkedListArenaEntry<T, J>>,
+        J: ArenaIndex,
+    {
+        self.reserve(other.len())?;
+
+        while let Some(value) = other.pop_front() {
+            self.push_back(value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Moves the element at the given [`Link`] and all elements following it, in order, to the
+    /// back of the `other` list, one by one.
+    ///
+    /// This does not relink nodes: every element is popped from this list and pushed into
+    /// `other`, since each [`LinkedList`] owns its [`Arena`]. The operation is `O(k)` in the
+    /// number of moved elements, needs capacity for all of them in `other`, and invalidates
+    /// every [`Link`] to the moved elements. To split in `O(k)` while keeping the links valid,
+    /// allocate the lists from one [`ListArena`] and use [`SharedList::split_off`].
+    ///
+    /// Returns [`ListError::LinkBroken`] if the given link does not refer to a node in this
+    /// list. Memory for the moved elements is reserved upfront. If it cannot be reserved, an
+    /// error is returned and both lists are left unchanged.
+    pub fn split_off_values<W, J>(
+        &mut self,
+        link: &Link<I>,
+        other: &mut LinkedList<W, T, J>,
+    ) -> Result<(), ListError<W::Error>>
+    where
+        W: Vector<LinkedListArenaEntry<T, J>>,
+        J: ArenaIndex,
+    {
+        let split_len = self
+            .iter_links()
+            .rev()
+            .position(|(node_link, _)| &node_link == link)
+            .ok_or(ListError::LinkBroken)?
+            + 1;
+
+        other.reserve(split_len)?;
+
+        let mut cursor = other.cursor_back_mut();
+
+        for _ in 0..split_len {
+            let value = self.pop_back().ok_or(ListError::ListEmpty)?;
+            cursor.insert_after(value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns a [`Cursor`] positioned at the front of this list.
+    pub fn cursor_front(&self) -> Cursor<'_, V, T, I> {
+        Cursor {
+            list: self,
+            current: self.header.head,
+        }
+    }
+
+    /// Returns a [`Cursor`] positioned at the back of this list.
+    pub fn cursor_back(&self) -> Cursor<'_, V, T, I> {
+        Cursor {
+            list: self,
+            current: self.header.tail,
+        }
+    }
+
+    /// Returns a [`Cursor`] positioned at the node at the given [`Link`], or `None` if the
+    /// link does not refer to a node in this list.
+    pub fn cursor_at(&self, link: &Link<I>) -> Option<Cursor<'_, V, T, I>> {
+        self.nodes.get_node(link)?;
+
+        Some(Cursor {
+            list: self,
+            current: Some(*link),
+        })
+    }
+
+    /// Returns a [`CursorMut`] positioned at the front of this list.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, V, T, I> {
+        CursorMut {
+            current: self.header.head,
+            list: self,
+        }
+    }
+
+    /// Returns a [`CursorMut`] positioned at the back of this list.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, V, T, I> {
+        CursorMut {
+            current: self.header.tail,
+            list: self,
+        }
+    }
+
+    /// Returns a [`CursorMut`] positioned at the node at the given [`Link`], or `None` if the
+    /// link does not refer to a node in this list.
+    pub fn cursor_at_mut(&mut self, link: &Link<I>) -> Option<CursorMut<'_, V, T, I>> {
+        self.nodes.get_node(link)?;
+
+        Some(CursorMut {
+            list: self,
+            current: Some(*link),
+        })
+    }
+
+    /// Sorts this list with the given comparator function, preserving the order of equal
+    /// elements.
+    ///
+    /// The nodes are sorted in place by relinking them, so the [`Link`]s to all elements remain
+    /// valid. See [`SharedList::sort_by`].
+    pub fn sort_by<F>(&mut self, cmp: F)
+    where
+        F: FnMut(&T, &T) -> core::cmp::Ordering,
+    {
+        self.header.sort_by(&mut self.nodes, cmp)
+    }
+
+    /// Sorts this list with the given key extraction function, preserving the order of
+    /// elements with equal keys. The [`Link`]s to all elements remain valid.
+    pub fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.header.sort_by_key(&mut self.nodes, f)
+    }
+
+    /// Retains only the elements for which the given predicate returns `true`, visiting the
+    /// elements from front to back. The [`Link`]s to the retained elements remain valid.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.header.retain(&mut self.nodes, f)
+    }
+
+    /// Returns the [`Link`] to the first node, from the front, whose element satisfies the
+    /// given predicate.
+    pub fn find<F>(&self, f: F) -> Option<Link<I>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.header.find(&self.nodes, f)
+    }
+
+    /// Returns the position, from the front, of the first element satisfying the given
+    /// predicate.
+    pub fn position<F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.header.position(&self.nodes, f)
+    }
+
+    /// Returns the [`Link`] to the node at the given position from the front of this list.
+    /// See [`SharedList::nth_link`].
+    pub fn nth_link(&self, n: usize) -> Option<Link<I>> {
+        self.header.nth_link(&self.nodes, n)
+    }
+
+    /// Rotates this list `n` places to the left, moving the first `n` elements to the back.
+    /// See [`SharedList::rotate_left`].
+    pub fn rotate_left(&mut self, n: usize) -> Option<()> {
+        self.header.rotate_left(&mut self.nodes, n)
+    }
+
+    /// Rotates this list `n` places to the right, moving the last `n` elements to the front.
+    /// See [`SharedList::rotate_right`].
+    pub fn rotate_right(&mut self, n: usize) -> Option<()> {
+        self.header.rotate_right(&mut self.nodes, n)
+    }
+
+    /// Returns an iterator to iterate over the elements in this list, from front to back,
+    /// along with references to the [`Link`]s to their nodes.
+    pub fn iter(&self) -> Iter<'_, V, T, I> {
+        self.header.iter(&self.nodes)
+    }
+
+    /// Returns an iterator to iterate over the elements in this list, from front to back,
+    /// along with the [`Link`]s to their nodes.
+    pub fn iter_links(&self) -> IterLinks<'_, V, T, I> {
+        self.header.iter_links(&self.nodes)
+    }
+
+    /// Calls the given closure with a mutable reference to every element in this list, from
+    /// front to back.
+    pub fn for_each_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.header.for_each_mut(&mut self.nodes, f)
+    }
+
+    /// Returns an iterator to iterate over mutable references to the elements in this list,
+    /// in no particular order.
+    ///
+    /// Elements are yielded in the order of their nodes in the underlying [`Arena`], which
+    /// differs from the order of the elements in this list once elements have been moved or
+    /// removed. Use [`LinkedList::for_each_mut`] or a [`CursorMut`] to visit the elements in
+    /// order; see [Mutable iteration](LinkedList#mutable-iteration) for why no ordered
+    /// iterator is provided.
+    pub fn values_mut_unordered(&mut self) -> ValuesMutUnordered<'_, T, I> {
+        ValuesMutUnordered {
+            nodes: self.nodes.backing_arena.iter_mut(),
+        }
+    }
+
+    /// Checks the internal consistency of this list and its underlying [`Arena`].
+    ///
+    /// Walks the nodes from the head, verifying that every link belongs to this list, that
+    /// the previous and next links of adjacent nodes are symmetric, and that the length, tail
+    /// and number of allocated nodes agree with the nodes reached. Returns the first
+    /// [`ListViolation`] found.
+    ///
+    /// This operation is `O(n)` in the capacity of this list.
+    pub fn validate(&self) -> Result<(), ListViolation<I>> {
+        self.nodes.validate()?;
+        self.header.validate(&self.nodes)?;
+
+        let (nodes, linked) = (self.nodes.len(), self.header.len());
+
+        if nodes != linked {
+            return Err(ListViolation::UnlinkedNodes { nodes, linked });
+        }
+
+        Ok(())
+    }
+}
+
+/// Dumps the internal state of a [`LinkedList`], including its underlying [`Arena`].
+impl<V, T, I> Debug for LinkedList<V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    T: Debug,
+    I: ArenaIndex,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LinkedList")
+            .field("head", &self.header.head)
+            .field("tail", &self.header.tail)
+            .field("len", &self.header.len)
+            .field("backing_arena", &self.nodes.backing_arena)
+            .finish()
+    }
+}
+
+impl<V, T, I> Default for LinkedList<V, T, I>
+where
+    V: Default + Vector<LinkedListArenaEntry<T, I>>,
+    I: IndexFor<Node<T, I>>,
+{
+    fn default() -> Self {
+        Self::with_backing_vector(V::default())
+    }
+}
+
+/// Iterator over the elements in a [`LinkedList`] along with references to the [`Link`]s to
+/// their nodes.
+pub struct Iter<'a, V, T, I = Index>
+where
+    I: ArenaIndex,
+{
+    nodes: &'a ListArena<V, T, I>,
+    front: Option<&'a Link<I>>,
+    back: Option<&'a Link<I>>,
+    remaining: usize,
+}
+
+impl<'a, V, T, I> Iterator for Iter<'a, V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    type Item = (&'a Link<I>, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let link = self.front?;
+        let node = self.nodes.get_node(link)?;
+
+        self.front = node.next.as_ref();
+        self.remaining -= 1;
+
+        Some((link, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<V, T, I> DoubleEndedIterator for Iter<'_, V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let link = self.back?;
+        let node = self.nodes.get_node(link)?;
+
+        self.back = node.prev.as_ref();
+        self.remaining -= 1;
+
+        Some((link, &node.value))
+    }
+}
+
+impl<V, T, I> ExactSizeIterator for Iter<'_, V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+}
+
+/// Iterator over the elements in a [`LinkedList`] along with the [`Link`]s to their nodes.
+pub struct IterLinks<'a, V, T, I = Index>
+where
+    I: ArenaIndex,
+{
+    iter: Iter<'a, V, T, I>,
+}
+
+impl<'a, V, T, I> Iterator for IterLinks<'a, V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    type Item = (Link<I>, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(link, value)| (*link, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<V, T, I> DoubleEndedIterator for IterLinks<'_, V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(link, value)| (*link, value))
+    }
+}
+
+impl<V, T, I> ExactSizeIterator for IterLinks<'_, V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+}
+
+/// Iterator over mutable references to the elements in a [`LinkedList`], in the order of
+/// their nodes in the underlying [`Arena`] rather than the order of the list.
+pub struct ValuesMutUnordered<'a, T, I = Index>
+where
+    I: ArenaIndex,
+{
+    nodes: ArenaIterMut<'a, Node<T, I>, I>,
+}
+
+impl<'a, T, I> Iterator for ValuesMutUnordered<'a, T, I>
+where
+    I: ArenaIndex,
+{
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nodes.next().map(|(_, node)| &mut node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.nodes.size_hint()
+    }
+}
+
+impl<T, I> ExactSizeIterator for ValuesMutUnordered<'_, T, I> where I: ArenaIndex {}
+
+/// Owning iterator over the elements in a [`LinkedList`].
+pub struct IntoIter<V, T, I = Index>
+where
+    I: ArenaIndex,
+{
+    list: LinkedList<V, T, I>,
+}
+
+impl<V, T, I> Iterator for IntoIter<V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<V, T, I> DoubleEndedIterator for IntoIter<V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<V, T, I> ExactSizeIterator for IntoIter<V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+}
+
+impl<'a, V, T, I> IntoIterator for &'a LinkedList<V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    type Item = (&'a Link<I>, &'a T);
+
+    type IntoIter = Iter<'a, V, T, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<V, T, I> IntoIterator for LinkedList<V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    type Item = T;
+
+    type IntoIter = IntoIter<V, T, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+/// A cursor over the nodes of a [`LinkedList`].
+///
+/// A cursor is positioned either at a node, or at a "ghost" position past the back and before
+/// the front of the list. Moving past either end of the list moves the cursor to the ghost
+/// position, and moving from the ghost position wraps around to the other end.
+pub struct Cursor<'a, V, T, I = Index>
+where
+    I: ArenaIndex,
+{
+    list: &'a LinkedList<V, T, I>,
+    current: Option<Link<I>>,
+}
+
+impl<V, T, I> Clone for Cursor<'_, V, T, I>
+where
+    I: ArenaIndex,
+{
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list,
+            current: self.current,
+        }
+    }
+}
+
+impl<'a, V, T, I> Cursor<'a, V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    /// Returns the [`Link`] to the node at the position of this cursor, or `None` at the ghost
+    /// position.
+    pub fn link(&self) -> Option<Link<I>> {
+        self.current
+    }
+
+    /// Returns the element at the position of this cursor, or `None` at the ghost position.
+    pub fn current(&self) -> Option<&'a T> {
+        self.list.get(self.current.as_ref()?)
+    }
+
+    /// Returns the element following the position of this cursor.
+    pub fn peek_next(&self) -> Option<&'a T> {
+        self.list.get(&self.next()?)
+    }
+
+    /// Returns the element preceding the position of this cursor.
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        self.list.get(&self.prev()?)
+    }
+
+    /// Moves this cursor to the next node.
+    pub fn move_next(&mut self) {
+        self.current = self.next();
+    }
+
+    /// Moves this cursor to the previous node.
+    pub fn move_prev(&mut self) {
+        self.current = self.prev();
+    }
+
+    fn next(&self) -> Option<Link<I>> {
+        match self.current {
+            Some(link) => self.list.next_link(&link).copied(),
+            None => self.list.header.head,
+        }
+    }
+
+    fn prev(&self) -> Option<Link<I>> {
+        match self.current {
+            Some(link) => self.list.prev_link(&link).copied(),
+            None => self.list.header.tail,
+        }
+    }
+}
+
+/// A cursor over the nodes of a [`LinkedList`], with support for editing the list.
+///
+/// Positioned like a [`Cursor`]. Elements are inserted and removed relative to the position of
+/// the cursor.
+pub struct CursorMut<'a, V, T, I = Index>
+where
+    I: ArenaIndex,
+{
+    list: &'a mut LinkedList<V, T, I>,
+    current: Option<Link<I>>,
+}
+
+impl<V, T, I> CursorMut<'_, V, T, I>
+where
+    V: Vector<LinkedListArenaEntry<T, I>>,
+    I: ArenaIndex,
+{
+    /// Returns a read-only [`Cursor`] at the position of this cursor.
+    pub fn as_cursor(&self) -> Cursor<'_, V, T, I> {
+        Cursor {
+            list: self.list,
+            current: self.current,
+        }
+    }
+
+    /// Returns the [`Link`] to the node at the position of this cursor, or `None` at the ghost
+    /// position.
+    pub fn link(&self) -> Option<Link<I>> {
+        self.current
+    }
+
+    /// Returns a mutable reference to the element at the position of this cursor, or `None`
+    /// at the ghost position.
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.list.get_mut(self.current.as_ref()?)
+    }
+
+    /// Returns a mutable reference to the element following the position of this cursor.
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = self.as_cursor().next()?;
+        self.list.get_mut(&next)
+    }
+
+    /// Returns a mutable reference to the element preceding the position of this cursor.
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = self.as_cursor().prev()?;
+        self.list.get_mut(&prev)
+    }
+
+    /// Moves this cursor to the next node.
+    pub fn move_next(&mut self) {
+        self.current = self.as_cursor().next();
+    }
+
+    /// Moves this cursor to the previous node.
+    pub fn move_prev(&mut self) {
+        self.current = self.as_cursor().prev();
+    }
+
+    /// Inserts the given element before the position of this cursor, and returns the [`Link`]
+    /// to its node. At the ghost position, the element is inserted at the back of the list.
+    pub fn insert_before(&mut self, value: T) -> Result<Link<I>, ListError<V::Error>> {
+        match self.current {
+            Some(link) => self.list.insert_before(&link, value),
+            None => self.list.push_back(value),
+        }
+    }
+
+    /// Inserts the given element after the position of this cursor, and returns the [`Link`]
+    /// to its node. At the ghost position, the element is inserted at the front of the list.
+    pub fn insert_after(&mut self, value: T) -> Result<Link<I>, ListError<V::Error>> {
+        match self.current {
+            Some(link) => self.list.insert_after(&link, value),
+            None => self.list.push_front(value),
+        }
+    }
+
+    /// Removes the element at the position of this cursor and moves this cursor to the next
+    /// node. Returns `None` at the ghost position.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let link = self.current?;
+        let next = self.as_cursor().next();
+
+        let value = self.list.remove(&link)?;
+        self.current = next;
+
+        Some(value)
+    }
+
+    /// Moves the elements of the given list, in order, after the position of this cursor, one
+    /// by one. At the ghost position, the elements are moved to the front of the list.
+    ///
+    /// The nodes of `other` are not spliced in: since each [`LinkedList`] owns its [`Arena`],
+    /// every element is popped from `other` and inserted into this list. The operation is
+    /// `O(n)` in the length of `other`, needs capacity for all of its elements in this list,
+    /// and invalidates every [`Link`] to the moved elements.
+    ///
+    /// Memory for the moved elements is reserved upfront. If it cannot be reserved, an error
+    /// is returned and both lists are left unchanged.
+    pub fn insert_list_after<W, J>(
+        &mut self,
+        other: &mut LinkedList<W, T, J>,
+    ) -> Result<(), ListError<V::Error>>
+    where
+        W: Vector<LinkedListArenaEntry<T, J>>,
+        J: ArenaIndex,
+    {
+        self.list.reserve(other.len())?;
+
+        while let Some(value) = other.pop_back() {
+            match self.current {
+                Some(link) => self.list.insert_after(&link, value)?,
+                None => self.list.push_front(value)?,
+            };
+        }
+
+        Ok(())
+    }
+
+    /// Moves the elements of the given list, in order, before the position of this cursor, one
+    /// by one. At the ghost position, the elements are moved to the back of the list.
+    ///
+    /// The nodes of `other` are not spliced in: since each [`LinkedList`] owns its [`Arena`],
+    /// every element is popped from `other` and inserted into this list. The operation is
+    /// `O(n)` in the length of `other`, needs capacity for all of its elements in this list,
+    /// and invalidates every [`Link`] to the moved elements.
+    ///
+    /// Memory for the moved elements is reserved upfront. If it cannot be reserved, an error
+    /// is returned and both lists are left unchanged.
+    pub fn insert_list_before<W, J>(
+        &mut self,
+        other: &mut LinkedList<W, T, J>,
+    ) -> Result<(), ListError<V::Error>>
+    where
+        W: Vector<LinkedListArenaEntry<T, J>>,
+        J: ArenaIndex,
+    {
+        self.list.reserve(other.len())?;
+
+        while let Some(value) = other.pop_front() {
+            match self.current {
+                Some(link) => self.list.insert_before(&link, value)?,
+                None => self.list.push_back(value)?,
+            };
+        }
+
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+pub mod tests {
+    use super::{
+        super::super::{
+            arena::{tests::Overcommitted, ArenaError, Entry},
+            collections::list::ListError,
+            vector::Vector,
+        },
+        Link, LinkedList, ListArena, ListViolation, Node, SharedList,
+    };
+    use core::fmt::Debug;
+
+    pub fn _test_list_invariants<T, V>(mut list: LinkedList<V, T>)
+    where
+        V: Vector<Entry<Node<T>>>,
+        T: Debug + PartialEq + Default,
+    {
+        list.clear().unwrap();
+
+        let capacity = list.capacity();
+
+        assert!(list.is_empty());
+
+        assert_eq!(list.peek_front(), None);
+        assert_eq!(list.peek_back(), None);
+
+        for _ in 0..capacity {
+            list.push_back(T::default()).unwrap();
+        }
+
+        assert!(list.len() == list.capacity());
+
+        let mut i = 0;
+        for (_, t) in &list {
+            assert_eq!(t, &T::default());
+            i += 1;
+        }
+
+        assert_eq!(i, list.len());
+
+        assert_eq!(list.peek_front(), Some(&T::default()));
+        assert_eq!(list.peek_back(), Some(&T::default()));
 
         match list.push_front(T::default()) {
             Err(ListError::ArenaError(ArenaError::OutOfMemory)) => {}
             _ => unreachable!("Out of memory not triggered"),
         };
 
-        match list.push_back(T::default()) {
-            Err(ListError::ArenaError(ArenaError::OutOfMemory)) => {}
-            _ => unreachable!("Out of memory not triggered"),
-        };
+        match list.push_back(T::default()) {
+            Err(ListError::ArenaError(ArenaError::OutOfMemory)) => {}
+            _ => unreachable!("Out of memory not triggered"),
+        };
+
+        const ADDITIONAL: usize = 5;
+
+        let result = list.reserve(ADDITIONAL);
+
+        for _ in 0..ADDITIONAL {
+            if result.is_ok() {
+                list.push_front(T::default()).unwrap();
+            }
+        }
+
+        let result = list.reserve(ADDITIONAL);
+
+        for _ in 0..ADDITIONAL {
+            if result.is_ok() {
+                list.push_front(T::default()).unwrap();
+            }
+        }
+
+        list.clear().unwrap();
+
+        assert!(list.is_empty());
+    }
+
+    pub fn _test_list_front_push_peek_pop_consistency<V>(mut list: LinkedList<V, i32>)
+    where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        list.clear().unwrap();
+
+        let capacity = list.capacity();
+
+        assert!(list.is_empty());
+        assert_eq!(list.peek_front(), None);
+        assert_eq!(list.pop_front(), None);
+
+        for ele in 0..capacity {
+            list.push_front(ele as i32).unwrap();
+        }
+
+        match list.push_front(0) {
+            Err(ListError::ArenaError(ArenaError::OutOfMemory)) => {}
+            _ => unreachable!("Out of memory not triggered"),
+        };
+
+        assert_eq!(list.peek_front().unwrap(), &(capacity as i32 - 1));
+
+        let mut i = capacity as i32 - 1;
+        for (_, ele) in &list {
+            assert_eq!(ele, &i);
+            i -= 1;
+        }
+        assert_eq!(i, -1);
+
+        let mut i = capacity as i32 - 1;
+        while let Some(ele) = list.pop_front() {
+            assert_eq!(ele, i);
+            i -= 1;
+        }
+        assert_eq!(i, -1);
+
+        assert!(list.is_empty());
+    }
+
+    pub fn _test_list_back_push_peek_pop_consistency<V>(mut list: LinkedList<V, i32>)
+    where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        list.clear().unwrap();
+
+        let capacity = list.capacity();
+
+        assert!(list.is_empty());
+        assert_eq!(list.peek_back(), None);
+        assert_eq!(list.pop_back(), None);
+
+        for ele in 0..capacity {
+            list.push_back(ele as i32).unwrap();
+        }
+
+        match list.push_back(0) {
+            Err(ListError::ArenaError(ArenaError::OutOfMemory)) => {}
+            _ => unreachable!("Out of memory not triggered"),
+        };
+
+        assert_eq!(list.peek_back().unwrap(), &(capacity as i32 - 1));
+
+        let mut i = 0;
+        for (_, ele) in &list {
+            assert_eq!(ele, &i);
+            i += 1;
+        }
+        assert_eq!(i as usize, capacity);
+
+        let mut i = capacity as i32 - 1;
+        while let Some(ele) = list.pop_back() {
+            assert_eq!(ele, i);
+            i -= 1;
+        }
+        assert_eq!(i, -1);
+
+        assert!(list.is_empty());
+    }
+
+    pub fn _test_list_remove<V>(mut list: LinkedList<V, i32>)
+    where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        let capacity = list.capacity();
+
+        assert!(capacity >= 3, "Test not valid for lists with capacity < 3 ");
+
+        list.clear().unwrap();
+        assert!(list.is_empty());
+
+        for ele in 0..capacity {
+            list.push_back(ele as i32).unwrap();
+        }
+
+        let link = *list.iter().find(|&(_, value)| value & 1 == 1).unwrap().0;
+
+        list.remove(&link).unwrap();
+
+        assert!(list.remove(&link).is_none());
+
+        assert!(list.get(&link).is_none());
+
+        assert_eq!(list.len(), list.capacity() - 1);
+
+        for (_, ele) in &list {
+            assert_ne!(ele, &1);
+        }
+
+        let link = *list.iter().find(|&(_, value)| value & 1 == 0).unwrap().0;
+
+        list.remove(&link).unwrap();
+
+        assert_eq!(list.peek_front(), Some(&2));
+
+        assert_eq!(list.len(), list.capacity() - 2);
+
+        let mut link = None;
+
+        for (l, _) in &list {
+            link = Some(l);
+        }
+
+        let link = *link.unwrap();
+
+        list.remove(&link).unwrap();
+
+        assert_eq!(list.len(), list.capacity() - 3);
+    }
+
+    pub fn _test_list_shift_push<V>(mut list: LinkedList<V, i32>)
+    where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        let capacity = list.capacity();
+
+        assert!(capacity >= 3, "Test not valid for lists with capacity < 3 ");
+
+        list.clear().unwrap();
+        assert!(list.is_empty());
+
+        for ele in 0..capacity {
+            list.push_back(ele as i32).unwrap();
+        }
+
+        assert_eq!(list.peek_front(), Some(&0));
+
+        let link = *list.iter().find(|&(_, value)| value & 1 == 1).unwrap().0;
+
+        assert_eq!(list.len(), list.capacity());
+
+        list.shift_push_front(&link).unwrap();
+
+        assert_eq!(list.len(), list.capacity());
+
+        assert_eq!(list.peek_front(), Some(&1));
+
+        for (i, j) in list
+            .iter()
+            .take(3)
+            .map(|(_, value)| value)
+            .zip([1, 0, 2].iter())
+        {
+            assert_eq!(i, j);
+        }
+
+        let link = *list.iter().find(|&(_, value)| value & 1 == 0).unwrap().0;
+
+        assert_eq!(list.get(&link), Some(&0));
+
+        assert_ne!(list.peek_back(), Some(&0));
+
+        assert_eq!(list.len(), list.capacity());
+
+        list.shift_push_back(&link).unwrap();
+
+        assert_eq!(list.peek_back(), Some(&0));
+
+        assert_eq!(list.len(), list.capacity());
+    }
+
+    pub fn _test_list_link_navigation<V>(mut list: LinkedList<V, i32>)
+    where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        let capacity = list.capacity();
+
+        assert!(capacity >= 3, "Test not valid for lists with capacity < 3 ");
+
+        list.clear().unwrap();
+
+        assert!(list.peek_front_link().is_none());
+        assert!(list.peek_back_link().is_none());
+
+        for ele in 0..capacity {
+            list.push_back(ele as i32).unwrap();
+        }
+
+        let mut link = list.peek_front_link().copied();
+        let mut i = 0;
+
+        while let Some(l) = link {
+            assert_eq!(list.get(&l), Some(&i));
+            link = list.next_link(&l).copied();
+            i += 1;
+        }
+
+        assert_eq!(i as usize, capacity);
+
+        let mut link = list.peek_back_link().copied();
+
+        while let Some(l) = link {
+            i -= 1;
+            assert_eq!(list.get(&l), Some(&i));
+            link = list.prev_link(&l).copied();
+        }
+
+        assert_eq!(i, 0);
+
+        let front = *list.peek_front_link().unwrap();
+        list.pop_front().unwrap();
+
+        assert!(list.next_link(&front).is_none());
+        assert!(list.prev_link(list.peek_front_link().unwrap()).is_none());
+    }
+
+    pub fn _test_list_shrink_to<V>(mut list: LinkedList<V, i32>)
+    where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        let capacity = list.capacity();
+
+        assert!(capacity >= 4, "Test not valid for lists with capacity < 4 ");
+
+        list.clear().unwrap();
+
+        for ele in 0..capacity {
+            list.push_back(ele as i32).unwrap();
+        }
+
+        for _ in 0..capacity / 2 {
+            list.pop_front().unwrap();
+        }
+
+        let old_back_link = *list.peek_back_link().unwrap();
+
+        let mut relocations = 0;
+
+        list.shrink_to(0, |ele, old, new| {
+            assert!(*ele as usize >= capacity / 2);
+            assert_ne!(old, new);
+            relocations += 1;
+        });
+
+        assert_eq!(relocations, list.len());
+        assert_eq!(list.capacity(), list.len());
+
+        assert!(list.get(&old_back_link).is_none());
+
+        let mut i = (capacity / 2) as i32;
+        for (_, ele) in &list {
+            assert_eq!(ele, &i);
+            i += 1;
+        }
+        assert_eq!(i as usize, capacity);
+
+        let mut link = list.peek_back_link().copied();
+        while let Some(l) = link {
+            i -= 1;
+            assert_eq!(list.get(&l), Some(&i));
+            link = list.prev_link(&l).copied();
+        }
+        assert_eq!(i as usize, capacity / 2);
+
+        match list.push_back(0) {
+            Err(ListError::ArenaError(ArenaError::OutOfMemory)) => {}
+            _ => unreachable!("Out of memory not triggered"),
+        };
+
+        list.reserve(capacity - list.len()).unwrap();
+
+        assert_eq!(list.capacity(), capacity);
+
+        while list.len() < capacity {
+            list.push_front(0).unwrap();
+        }
+
+        assert_eq!(list.peek_back(), Some(&(capacity as i32 - 1)));
+    }
+
+    pub fn _test_list_try_with_backing_vector<T, V>(bounded_vec: V, test_vec: V)
+    where
+        V: Vector<Entry<Node<T>>>,
+    {
+        match LinkedList::<_, T>::try_with_backing_vector(Overcommitted(bounded_vec)) {
+            Err(ListError::ArenaError(ArenaError::VectorError(_))) => {}
+            _ => unreachable!("Wrong error on overcommitted vector."),
+        };
+
+        let capacity = test_vec.capacity();
+
+        let list = LinkedList::<_, T>::try_with_backing_vector(test_vec).unwrap();
+
+        assert_eq!(list.capacity(), capacity);
+        assert!(list.is_empty());
+    }
+
+    pub fn _test_list_compact<V>(mut list: LinkedList<V, i32>)
+    where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        let capacity = list.capacity();
+
+        assert!(capacity >= 4, "Test not valid for lists with capacity < 4 ");
+
+        list.clear().unwrap();
+
+        let mut links = [None, None];
+
+        for ele in 0..capacity {
+            let link = list.push_back(ele as i32).unwrap();
+
+            if ele >= capacity - 2 {
+                links[ele + 2 - capacity] = Some(link);
+            }
+        }
+
+        for _ in 0..capacity / 2 {
+            list.pop_front().unwrap();
+        }
+
+        list.compact(|ele, old, new| {
+            for link in links.iter_mut().flatten() {
+                if *link == old {
+                    *link = new;
+                }
+            }
+
+            assert!(*ele as usize >= capacity / 2);
+        });
+
+        assert_eq!(list.capacity(), list.len());
+
+        for (i, link) in links.iter().enumerate() {
+            assert_eq!(list.get(&link.unwrap()), Some(&((capacity - 2 + i) as i32)));
+        }
+
+        assert!(list
+            .iter()
+            .map(|(_, ele)| *ele)
+            .eq((capacity / 2) as i32..capacity as i32));
+    }
+
+    pub fn _test_list_validate<V>(mut list: LinkedList<V, i32>)
+    where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        assert!(
+            list.capacity() >= 4,
+            "Test not valid for lists with capacity < 4 "
+        );
+
+        list.clear().unwrap();
+        list.validate().unwrap();
+
+        let links = [0, 1, 2, 3].map(|ele| list.push_back(ele).unwrap());
+
+        list.validate().unwrap();
+
+        list.header.len -= 1;
+        assert_eq!(
+            list.validate(),
+            Err(ListViolation::LinkedBeyondLen { link: links[3] })
+        );
+
+        list.header.len += 2;
+        assert_eq!(
+            list.validate(),
+            Err(ListViolation::LenMismatch { len: 5, linked: 4 })
+        );
+        list.header.len -= 1;
+
+        list.header.tail = Some(links[2]);
+        assert_eq!(
+            list.validate(),
+            Err(ListViolation::TailMismatch {
+                tail: Some(links[2]),
+                last: Some(links[3])
+            })
+        );
+        list.header.tail = Some(links[3]);
+
+        list.nodes.get_node_mut(&links[2]).unwrap().prev = Some(links[0]);
+        assert_eq!(
+            list.validate(),
+            Err(ListViolation::PrevMismatch {
+                link: links[2],
+                prev: Some(links[0]),
+                expected: Some(links[1])
+            })
+        );
+        list.nodes.get_node_mut(&links[2]).unwrap().prev = Some(links[1]);
+
+        list.validate().unwrap();
+
+        list.remove(&links[0]).unwrap();
+
+        list.header.head = Some(links[0]);
+        assert_eq!(
+            list.validate(),
+            Err(ListViolation::LinkBroken { link: links[0] })
+        );
+        list.header.head = Some(links[1]);
+
+        let index = list
+            .nodes
+            .backing_arena
+            .insert(Node::with_value(42))
+            .unwrap();
+        assert_eq!(
+            list.validate(),
+            Err(ListViolation::UnlinkedNodes {
+                nodes: 4,
+                linked: 3
+            })
+        );
+        list.nodes.backing_arena.remove(&index).unwrap();
+
+        list.validate().unwrap();
+    }
+
+    pub fn _test_list_cursor<V>(mut list: LinkedList<V, i32>)
+    where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        assert!(
+            list.capacity() >= 8,
+            "Test not valid for lists with capacity < 8"
+        );
+
+        list.clear().unwrap();
+
+        let mut cursor = list.cursor_front();
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+
+        let links = [0, 1, 2, 3].map(|ele| list.push_back(ele).unwrap());
+
+        let mut cursor = list.cursor_front();
+        assert_eq!(cursor.link(), Some(links[0]));
+        assert_eq!(cursor.current(), Some(&0));
+        assert_eq!(cursor.peek_prev(), None);
+        assert_eq!(cursor.peek_next(), Some(&1));
+
+        cursor.move_prev();
+        assert_eq!(cursor.link(), None);
+        assert_eq!(cursor.peek_next(), Some(&0));
+        assert_eq!(cursor.peek_prev(), Some(&3));
+
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&3));
+
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&0));
+
+        let cursor = list.cursor_back();
+        assert_eq!(cursor.link(), Some(links[3]));
+        assert_eq!(cursor.current(), Some(&3));
+
+        let cursor = list.cursor_at(&links[2]).unwrap();
+        assert_eq!(cursor.current(), Some(&2));
+        assert_eq!(cursor.peek_prev(), Some(&1));
+
+        let mut cursor = list.cursor_at_mut(&links[1]).unwrap();
+
+        *cursor.current().unwrap() = 10;
+        *cursor.peek_next().unwrap() = 20;
+
+        let link_5 = cursor.insert_before(5).unwrap();
+        let link_15 = cursor.insert_after(15).unwrap();
+        assert_eq!(cursor.current(), Some(&mut 10));
+        assert_eq!(cursor.peek_prev(), Some(&mut 5));
+        assert_eq!(cursor.peek_next(), Some(&mut 15));
+
+        assert_eq!(cursor.remove_current(), Some(10));
+        assert_eq!(cursor.link(), Some(link_15));
+
+        cursor.move_prev();
+        assert_eq!(cursor.link(), Some(link_5));
+
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.link(), None);
+        assert_eq!(cursor.remove_current(), None);
+
+        cursor.insert_after(-1).unwrap();
+        cursor.insert_before(4).unwrap();
+
+        assert!(list
+            .iter()
+            .map(|(_, ele)| *ele)
+            .eq([-1, 0, 5, 15, 20, 3, 4]));
+
+        assert!(list.cursor_at(&links[1]).is_none());
+        assert!(list.cursor_at_mut(&links[1]).is_none());
+
+        let mut cursor = list.cursor_back_mut();
+
+        while cursor.remove_current().is_some() {
+            cursor.move_prev();
+        }
+
+        assert_eq!(list.len(), 0);
+        assert!(list.validate().is_ok());
+    }
+
+    pub fn _test_list_cursor_insert_list<V>(
+        mut list: LinkedList<V, i32>,
+        mut other: LinkedList<V, i32>,
+    ) where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        assert!(
+            list.capacity() >= 8 && other.capacity() >= 8,
+            "Test not valid for lists with capacity < 8"
+        );
+
+        list.clear().unwrap();
+        other.clear().unwrap();
+
+        let links = [0, 1, 2].map(|ele| list.push_back(ele).unwrap());
+
+        let other_links = [10, 11].map(|ele| other.push_back(ele).unwrap());
+
+        list.cursor_at_mut(&links[0])
+            .unwrap()
+            .insert_list_after(&mut other)
+            .unwrap();
+
+        assert!(other.is_empty());
+        assert_eq!(other.get(&other_links[0]), None);
+        assert!(list.iter().map(|(_, ele)| *ele).eq([0, 10, 11, 1, 2]));
+
+        for ele in [20, 21] {
+            other.push_back(ele).unwrap();
+        }
+
+        list.cursor_at_mut(&links[2])
+            .unwrap()
+            .insert_list_before(&mut other)
+            .unwrap();
+
+        assert!(list
+            .iter()
+            .map(|(_, ele)| *ele)
+            .eq([0, 10, 11, 1, 20, 21, 2]));
+
+        other.push_back(30).unwrap();
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_prev();
+        cursor.insert_list_before(&mut other).unwrap();
+
+        other.push_back(-1).unwrap();
+        cursor.insert_list_after(&mut other).unwrap();
+
+        assert!(list
+            .iter()
+            .map(|(_, ele)| *ele)
+            .eq([-1, 0, 10, 11, 1, 20, 21, 2, 30]));
+        assert!(list.validate().is_ok());
+
+        // splicing beyond the capacity either grows the list or leaves both lists unchanged
+        while other.push_back(0).is_ok() {}
+
+        let (len, other_len) = (list.len(), other.len());
+
+        if list
+            .cursor_front_mut()
+            .insert_list_after(&mut other)
+            .is_err()
+        {
+            assert_eq!(list.len(), len);
+            assert_eq!(other.len(), other_len);
+        } else {
+            assert_eq!(list.len(), len + other_len);
+            assert!(other.is_empty());
+        }
+
+        assert!(list.validate().is_ok());
+    }
+
+    pub fn _test_list_insert_and_move<V>(mut list: LinkedList<V, i32>)
+    where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        assert!(
+            list.capacity() >= 8,
+            "Test not valid for lists with capacity < 8"
+        );
+
+        list.clear().unwrap();
+
+        let link_1 = list.push_back(1).unwrap();
+        let link_3 = list.push_back(3).unwrap();
+
+        let link_0 = list.insert_before(&link_1, 0).unwrap();
+        let link_2 = list.insert_after(&link_1, 2).unwrap();
+        let link_4 = list.insert_after(&link_3, 4).unwrap();
+
+        assert_eq!(list.peek_front_link(), Some(&link_0));
+        assert_eq!(list.peek_back_link(), Some(&link_4));
+        assert!(list.iter().map(|(_, ele)| *ele).eq(0..5));
 
-        const ADDITIONAL: usize = 5;
+        list.move_before(&link_4, &link_1).unwrap();
+        assert!(list.iter().map(|(_, ele)| *ele).eq([0, 4, 1, 2, 3]));
 
-        let result = list.reserve(ADDITIONAL);
+        list.move_after(&link_0, &link_3).unwrap();
+        assert!(list.iter().map(|(_, ele)| *ele).eq([4, 1, 2, 3, 0]));
+        assert_eq!(list.peek_back_link(), Some(&link_0));
 
-        for _ in 0..ADDITIONAL {
-            if result.is_ok() {
-                list.push_front(T::default()).unwrap();
-            }
-        }
+        list.move_before(&link_2, &link_4).unwrap();
+        assert!(list.iter().map(|(_, ele)| *ele).eq([2, 4, 1, 3, 0]));
+        assert_eq!(list.peek_front_link(), Some(&link_2));
 
-        let result = list.reserve(ADDITIONAL);
+        list.move_after(&link_2, &link_2).unwrap();
+        list.move_before(&link_3, &link_0).unwrap();
+        assert!(list.iter().map(|(_, ele)| *ele).eq([2, 4, 1, 3, 0]));
 
-        for _ in 0..ADDITIONAL {
-            if result.is_ok() {
-                list.push_front(T::default()).unwrap();
-            }
-        }
+        assert_eq!(list.get(&link_4), Some(&4));
+        assert!(list.validate().is_ok());
 
-        list.clear().unwrap();
+        list.remove(&link_1).unwrap();
 
-        assert!(list.is_empty());
+        assert!(matches!(
+            list.insert_after(&link_1, 5),
+            Err(ListError::LinkBroken)
+        ));
+        assert!(list.move_before(&link_1, &link_0).is_none());
+        assert!(list.move_after(&link_0, &link_1).is_none());
+
+        assert!(list.iter().map(|(_, ele)| *ele).eq([2, 4, 3, 0]));
+        assert_eq!(list.len(), 4);
+        assert!(list.validate().is_ok());
+
+        // shifted nodes are detached from their old neighbours
+        list.shift_push_back(&link_2).unwrap();
+        list.shift_push_front(&link_0).unwrap();
+
+        assert!(list.iter().map(|(_, ele)| *ele).eq([0, 4, 3, 2]));
+        assert!(list.validate().is_ok());
     }
 
-    pub fn _test_list_front_push_peek_pop_consistency<V>(mut list: LinkedList<V, i32>)
+    pub fn _test_list_iterators<V>(mut list: LinkedList<V, i32>)
     where
-        V: Vector<Entry<Node<i32>>>,
+        V: Vector<Entry<Node<i32>>> + Default,
     {
+        assert!(
+            list.capacity() >= 8,
+            "Test not valid for lists with capacity < 8"
+        );
+
         list.clear().unwrap();
 
-        let capacity = list.capacity();
+        assert_eq!(list.iter().next(), None);
+        assert_eq!(list.iter().next_back(), None);
 
-        assert!(list.is_empty());
-        assert_eq!(list.peek_front(), None);
-        assert_eq!(list.pop_front(), None);
+        list.try_extend([1, 2, 3, 4]).unwrap();
 
-        for ele in 0..capacity {
-            list.push_front(ele as i32).unwrap();
-        }
+        assert_eq!(list.iter().len(), 4);
+        assert!(list.iter().map(|(_, ele)| *ele).eq(1..5));
+        assert!(list.iter().rev().map(|(_, ele)| *ele).eq((1..5).rev()));
 
-        match list.push_front(0) {
-            Err(ListError::ArenaError(ArenaError::OutOfMemory)) => {}
-            _ => unreachable!("Out of memory not triggered"),
-        };
+        let mut iter = list.iter().map(|(_, ele)| ele);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
 
-        assert_eq!(list.peek_front().unwrap(), &(capacity as i32 - 1));
+        let (front_link, _) = list.iter().next().unwrap();
+        assert_eq!(list.peek_front_link(), Some(front_link));
 
-        let mut i = capacity as i32 - 1;
-        for (_, ele) in &list {
-            assert_eq!(ele, &i);
-            i -= 1;
+        assert_eq!(list.iter_links().len(), 4);
+
+        for (link, value) in list.iter_links() {
+            assert_eq!(list.get(&link), Some(value));
         }
-        assert_eq!(i, -1);
 
-        let mut i = capacity as i32 - 1;
-        while let Some(ele) = list.pop_front() {
-            assert_eq!(ele, i);
-            i -= 1;
+        let (back_link, _) = list.iter_links().next_back().unwrap();
+        assert_eq!(list.peek_back_link(), Some(&back_link));
+
+        for ele in list.values_mut_unordered() {
+            *ele *= 10;
         }
-        assert_eq!(i, -1);
 
-        assert!(list.is_empty());
+        assert_eq!(list.values_mut_unordered().len(), 4);
+
+        let mut visited = 0;
+
+        list.for_each_mut(|ele| {
+            visited += 1;
+            *ele += visited;
+        });
+
+        assert!(list.iter().map(|(_, ele)| *ele).eq([11, 22, 33, 44]));
+
+        list.shift_push_front(&back_link).unwrap();
+        list.for_each_mut(|ele| *ele %= 10);
+
+        assert!(list.iter().map(|(_, ele)| *ele).eq([4, 1, 2, 3]));
+
+        list.shift_push_back(&back_link).unwrap();
+        list.for_each_mut(|ele| *ele = *ele * 10 + *ele);
+
+        assert!(list.iter().map(|(_, ele)| *ele).eq([11, 22, 33, 44]));
+
+        let mut into_iter = list.into_iter();
+        assert_eq!(into_iter.len(), 4);
+        assert_eq!(into_iter.next_back(), Some(44));
+        assert_eq!(into_iter.next(), Some(11));
+        assert!(into_iter.eq([22, 33]));
+
+        let mut list = LinkedList::<V, i32>::default();
+
+        list.try_extend(0..4).unwrap();
+
+        assert_eq!(list.len(), 4);
+        assert!(list.validate().is_ok());
+
+        let excess = list.capacity() as i32 + 1;
+
+        match list.try_extend(4..4 + excess) {
+            Ok(()) => assert_eq!(list.len() as i32, 4 + excess),
+            Err(ListError::ArenaError(_)) => assert_eq!(list.len(), list.capacity()),
+            Err(_) => unreachable!("Wrong error on extending a full list."),
+        };
+
+        assert!(list.validate().is_ok());
+        assert!(list.into_iter().take(4).rev().eq((0..4).rev()));
     }
 
-    pub fn _test_list_back_push_peek_pop_consistency<V>(mut list: LinkedList<V, i32>)
+    pub fn _test_shared_lists<V>(mut arena: ListArena<V, i32>)
     where
         V: Vector<Entry<Node<i32>>>,
     {
-        list.clear().unwrap();
+        assert!(
+            arena.capacity() >= 8,
+            "Test not valid for arenas with capacity < 8"
+        );
 
-        let capacity = list.capacity();
+        let mut odd = arena.new_list();
+        let mut even = arena.new_list();
 
-        assert!(list.is_empty());
-        assert_eq!(list.peek_back(), None);
-        assert_eq!(list.pop_back(), None);
+        let mut links = [None; 8];
 
-        for ele in 0..capacity {
-            list.push_back(ele as i32).unwrap();
+        for i in 0..8 {
+            let list = if i % 2 == 0 { &mut even } else { &mut odd };
+            links[i as usize] = Some(list.push_back(&mut arena, i).unwrap());
         }
 
-        match list.push_back(0) {
-            Err(ListError::ArenaError(ArenaError::OutOfMemory)) => {}
-            _ => unreachable!("Out of memory not triggered"),
-        };
+        let links = links.map(Option::unwrap);
 
-        assert_eq!(list.peek_back().unwrap(), &(capacity as i32 - 1));
+        assert_eq!(arena.len(), 8);
+        assert_eq!(odd.len(), 4);
+        assert_eq!(even.len(), 4);
 
-        let mut i = 0;
-        for (_, ele) in &list {
-            assert_eq!(ele, &i);
-            i += 1;
+        assert!(odd.iter(&arena).map(|(_, ele)| *ele).eq([1, 3, 5, 7]));
+        assert!(even.iter(&arena).map(|(_, ele)| *ele).eq([0, 2, 4, 6]));
+
+        odd.transfer_to_front(&mut arena, &links[3], &mut even)
+            .unwrap();
+        even.transfer_to_back(&mut arena, &links[4], &mut odd)
+            .unwrap();
+
+        assert!(odd.iter(&arena).map(|(_, ele)| *ele).eq([1, 5, 7, 4]));
+        assert!(even.iter(&arena).map(|(_, ele)| *ele).eq([3, 0, 2, 6]));
+
+        assert_eq!(arena.get(&links[3]), Some(&3));
+        assert_eq!(even.peek_front_link(), Some(&links[3]));
+        assert_eq!(odd.peek_back_link(), Some(&links[4]));
+
+        assert!(odd
+            .transfer_to_back(&mut arena, &links[3], &mut even)
+            .is_none());
+
+        assert!(odd.iter(&arena).map(|(_, ele)| *ele).eq([1, 5, 7, 4]));
+        assert!(even.iter(&arena).map(|(_, ele)| *ele).eq([3, 0, 2, 6]));
+
+        odd.validate(&arena).unwrap();
+        even.validate(&arena).unwrap();
+
+        even.append(&mut arena, &mut odd).unwrap();
+
+        assert!(odd.is_empty());
+        assert_eq!(odd.len(), 0);
+        assert_eq!(even.len(), 8);
+        assert!(even
+            .iter(&arena)
+            .map(|(_, ele)| *ele)
+            .eq([3, 0, 2, 6, 1, 5, 7, 4]));
+        assert!(even
+            .iter(&arena)
+            .rev()
+            .map(|(_, ele)| *ele)
+            .eq([4, 7, 5, 1, 6, 2, 0, 3]));
+
+        odd.append(&mut arena, &mut even).unwrap();
+        assert!(even.is_empty());
+        assert_eq!(odd.len(), 8);
+
+        even.append(&mut arena, &mut odd).unwrap();
+
+        let mut tail = even.split_off(&mut arena, &links[1]).unwrap();
+
+        assert!(even.iter(&arena).map(|(_, ele)| *ele).eq([3, 0, 2, 6]));
+        assert!(tail.iter(&arena).map(|(_, ele)| *ele).eq([1, 5, 7, 4]));
+        assert_eq!(even.peek_back_link(), Some(&links[6]));
+        assert_eq!(tail.peek_front_link(), Some(&links[1]));
+
+        assert!(even.split_off(&mut arena, &links[5]).is_none());
+        assert_eq!(even.len(), 4);
+
+        let head = even.split_off(&mut arena, &links[3]).unwrap();
+        assert!(even.is_empty());
+        assert!(head.iter(&arena).map(|(_, ele)| *ele).eq([3, 0, 2, 6]));
+        even = head;
+
+        even.validate(&arena).unwrap();
+        tail.validate(&arena).unwrap();
+
+        assert_eq!(tail.remove(&mut arena, &links[7]), Some(7));
+        assert_eq!(even.pop_front(&mut arena), Some(3));
+        assert_eq!(arena.len(), 6);
+
+        tail.clear(&mut arena);
+        assert!(tail.is_empty());
+        assert_eq!(arena.len(), 3);
+
+        assert!(even.iter(&arena).map(|(_, ele)| *ele).eq([0, 2, 6]));
+
+        let front = *even.peek_front_link().unwrap();
+        let (new_front, old) = even.replace(&mut arena, &front, 42).unwrap();
+
+        assert_eq!(old, 0);
+        assert_ne!(new_front, front);
+        assert_eq!(arena.get(&front), None);
+        assert_eq!(even.peek_front_link(), Some(&new_front));
+        assert_eq!(even.replace(&mut arena, &front, 7), None);
+
+        let (new_middle, old) = even.replace(&mut arena, &links[2], 24).unwrap();
+
+        assert_eq!(old, 2);
+        assert_eq!(arena.next_link(&new_front), Some(&new_middle));
+        assert_eq!(arena.prev_link(&links[6]), Some(&new_middle));
+
+        let mut other = arena.new_list();
+        assert_eq!(other.replace(&mut arena, &new_front, 7), None);
+        assert_eq!(other.replace(&mut arena, &links[6], 7), None);
+
+        assert!(even.iter(&arena).map(|(_, ele)| *ele).eq([42, 24, 6]));
+        assert!(even.iter(&arena).rev().map(|(_, ele)| *ele).eq([6, 24, 42]));
+        assert_eq!(arena.len(), 3);
+
+        even.validate(&arena).unwrap();
+        arena.validate().unwrap();
+    }
+
+    pub fn _test_shared_lists_shrink_to<V>(mut arena: ListArena<V, i32>)
+    where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        let capacity = arena.capacity();
+
+        assert!(capacity >= 8, "Test not valid for arenas with capacity < 8");
+
+        let mut odd = arena.new_list();
+        let mut even = arena.new_list();
+
+        for ele in 0..capacity as i32 {
+            let list = if ele % 2 == 0 { &mut even } else { &mut odd };
+            list.push_back(&mut arena, ele).unwrap();
         }
-        assert_eq!(i as usize, capacity);
 
-        let mut i = capacity as i32 - 1;
-        while let Some(ele) = list.pop_back() {
-            assert_eq!(ele, i);
-            i -= 1;
+        for _ in 0..capacity / 4 {
+            odd.pop_front(&mut arena).unwrap();
+            even.pop_front(&mut arena).unwrap();
         }
-        assert_eq!(i, -1);
 
-        assert!(list.is_empty());
+        let expected = |parity| (parity..capacity as i32).step_by(2).skip(capacity / 4);
+
+        let mut relocations = 0;
+
+        arena.shrink_to(0, &mut [&mut odd, &mut even], |ele, old, new| {
+            assert!(*ele as usize >= capacity / 2);
+            assert_ne!(old, new);
+            relocations += 1;
+        });
+
+        assert!(relocations > 0);
+        assert_eq!(arena.capacity(), arena.len());
+
+        assert!(odd.iter(&arena).map(|(_, ele)| *ele).eq(expected(1)));
+        assert!(even.iter(&arena).map(|(_, ele)| *ele).eq(expected(0)));
+        assert!(odd
+            .iter(&arena)
+            .rev()
+            .map(|(_, ele)| *ele)
+            .eq(expected(1).rev()));
+
+        odd.validate(&arena).unwrap();
+        even.validate(&arena).unwrap();
+        arena.validate().unwrap();
     }
 
-    pub fn _test_list_remove<V>(mut list: LinkedList<V, i32>)
+    pub fn _test_shared_list_foreign_links<V>(mut arena: ListArena<V, i32>)
     where
         V: Vector<Entry<Node<i32>>>,
     {
-        let capacity = list.capacity();
+        assert!(
+            arena.capacity() >= 7,
+            "Test not valid for arenas with capacity < 7"
+        );
+
+        fn foreign_op<V>(
+            op: usize,
+            arena: &mut ListArena<V, i32>,
+            list: &mut SharedList<i32>,
+            other: &mut SharedList<i32>,
+            link: &Link,
+        ) -> Option<()>
+        where
+            V: Vector<Entry<Node<i32>>>,
+        {
+            let anchor = other.peek_front_link().copied();
+
+            match op {
+                0 => list.remove(arena, link).map(|_| ()),
+                1 => list.shift_push_front(arena, link),
+                2 => list.shift_push_back(arena, link),
+                3 => list.move_before(arena, link, &anchor?),
+                4 => list.move_after(arena, link, &anchor?),
+                5 => list.transfer_to_front(arena, link, other),
+                _ => list.transfer_to_back(arena, link, other),
+            }
+        }
 
-        assert!(capacity >= 3, "Test not valid for lists with capacity < 3 ");
+        for op in 0..7 {
+            for short in [true, false] {
+                arena.backing_arena.clear().unwrap();
+
+                let mut list = arena.new_list();
+                let mut sibling = arena.new_list();
+
+                for i in 0..3 {
+                    sibling.push_back(&mut arena, i).unwrap();
+                }
+
+                let link = sibling.nth_link(&arena, 1).unwrap();
+
+                let mut other = arena.new_list();
+                other.push_back(&mut arena, 10).unwrap();
+
+                if !short {
+                    for i in 3..6 {
+                        list.push_back(&mut arena, i).unwrap();
+                    }
+
+                    foreign_op(op, &mut arena, &mut list, &mut other, &link);
+
+                    assert!(sibling.validate(&arena).is_err());
+
+                    while list.pop_front(&mut arena).is_some() {}
+                    while sibling.pop_front(&mut arena).is_some() {}
+                    while other.pop_front(&mut arena).is_some() {}
+
+                    continue;
+                }
+
+                assert_eq!(
+                    foreign_op(op, &mut arena, &mut list, &mut other, &link),
+                    None
+                );
+
+                let head = *sibling.peek_front_link().unwrap();
+
+                assert_eq!(
+                    foreign_op(op, &mut arena, &mut other, &mut list, &head),
+                    None
+                );
+
+                assert!(list.is_empty());
+                assert!(sibling.iter(&arena).map(|(_, ele)| *ele).eq(0..3));
+                assert!(other.iter(&arena).map(|(_, ele)| *ele).eq([10]));
+
+                list.validate(&arena).unwrap();
+                sibling.validate(&arena).unwrap();
+                other.validate(&arena).unwrap();
+            }
+        }
+    }
+
+    pub fn _test_list_append_split_off_values<V>(
+        mut list: LinkedList<V, i32>,
+        mut other: LinkedList<V, i32>,
+    ) where
+        V: Vector<Entry<Node<i32>>>,
+    {
+        assert!(
+            list.capacity() >= 8 && other.capacity() >= 8,
+            "Test not valid for lists with capacity < 8"
+        );
 
         list.clear().unwrap();
+        other.clear().unwrap();
+
+        list.try_extend([1, 2, 3, 4]).unwrap();
+        other.try_extend([5, 6, 7, 8]).unwrap();
+
+        let link = *other.peek_front_link().unwrap();
+
+        list.append_values(&mut other).unwrap();
+
+        assert!(other.is_empty());
+        assert_eq!(other.get(&link), None);
+        assert!(list.iter().map(|(_, ele)| *ele).eq(1..9));
+        list.validate().unwrap();
+
+        list.append_values(&mut other).unwrap();
+        assert_eq!(list.len(), 8);
+
+        let link = list.iter_links().find(|(_, &value)| value == 6).unwrap().0;
+
+        other.push_back(0).unwrap();
+        list.split_off_values(&link, &mut other).unwrap();
+
+        assert!(list.iter().map(|(_, ele)| *ele).eq(1..6));
+        assert!(other.iter().map(|(_, ele)| *ele).eq([0, 6, 7, 8]));
+        assert_eq!(list.get(&link), None);
+
+        list.validate().unwrap();
+        other.validate().unwrap();
+
+        other.clear().unwrap();
+
+        let front_link = *list.peek_front_link().unwrap();
+        list.split_off_values(&front_link, &mut other).unwrap();
+
         assert!(list.is_empty());
+        assert!(other.iter().map(|(_, ele)| *ele).eq(1..6));
 
-        for ele in 0..capacity {
-            list.push_back(ele as i32).unwrap();
+        assert!(matches!(
+            list.split_off_values(&front_link, &mut other),
+            Err(ListError::LinkBroken)
+        ));
+
+        while other.len() < other.capacity() {
+            other.push_back(0).unwrap();
         }
 
-        let link = *list.iter().find(|&(_, value)| value & 1 == 1).unwrap().0;
+        list.try_extend([1, 2]).unwrap();
 
-        list.remove(&link).unwrap();
+        let front_link = *list.peek_front_link().unwrap();
+        let result = list.split_off_values(&front_link, &mut other);
 
-        assert!(list.remove(&link).is_none());
+        if result.is_err() {
+            assert!(list.iter().map(|(_, ele)| *ele).eq(1..3));
+            assert_eq!(other.len(), other.capacity());
+        } else {
+            assert!(list.is_empty());
+        }
 
-        assert!(list.get(&link).is_none());
+        other.validate().unwrap();
+    }
 
-        assert_eq!(list.len(), list.capacity() - 1);
+    pub fn _test_list_sort<V>(mut list: LinkedList<V, (i32, usize)>)
+    where
+        V: Vector<Entry<Node<(i32, usize)>>>,
+    {
+        assert!(
+            list.capacity() >= 11,
+            "Test not valid for lists with capacity < 11"
+        );
 
-        for (_, ele) in &list {
-            assert_ne!(ele, &1);
+        list.clear().unwrap();
+
+        list.sort_by_key(|&(key, _)| key);
+        assert!(list.is_empty());
+
+        let link = list.push_back((0, 0)).unwrap();
+        list.sort_by_key(|&(key, _)| key);
+        assert_eq!(list.peek_front_link(), Some(&link));
+        assert_eq!(list.peek_back_link(), Some(&link));
+
+        list.clear().unwrap();
+
+        let keys = [5, 3, 8, 3, 1, 5, 9, 0, 3, 8, 1];
+        let mut links = [None; 11];
+
+        for (seq, &key) in keys.iter().enumerate() {
+            links[seq] = Some(list.push_back((key, seq)).unwrap());
         }
 
-        let link = *list.iter().find(|&(_, value)| value & 1 == 0).unwrap().0;
+        let links = links.map(Option::unwrap);
 
-        list.remove(&link).unwrap();
+        list.sort_by_key(|&(key, _)| key);
 
-        assert_eq!(list.peek_front(), Some(&2));
+        assert_eq!(list.len(), keys.len());
 
-        assert_eq!(list.len(), list.capacity() - 2);
+        let mut sorted = list.iter().map(|(_, ele)| *ele);
+        let mut last = sorted.next().unwrap();
 
-        let mut link = None;
+        for ele in sorted {
+            assert!(ele.0 > last.0 || (ele.0 == last.0 && ele.1 > last.1));
+            last = ele;
+        }
 
-        for (l, _) in &list {
-            link = Some(l);
+        assert!(list
+            .iter()
+            .map(|(_, &(key, _))| key)
+            .eq([0, 1, 1, 3, 3, 3, 5, 5, 8, 8, 9]));
+
+        for (seq, link) in links.iter().enumerate() {
+            assert_eq!(list.get(link), Some(&(keys[seq], seq)));
         }
 
-        let link = *link.unwrap();
+        assert_eq!(list.peek_front_link(), Some(&links[7]));
+        assert_eq!(list.peek_back_link(), Some(&links[6]));
 
-        list.remove(&link).unwrap();
+        assert!(list
+            .iter()
+            .rev()
+            .map(|(_, &(key, _))| key)
+            .eq([9, 8, 8, 5, 5, 3, 3, 3, 1, 1, 0]));
 
-        assert_eq!(list.len(), list.capacity() - 3);
+        list.validate().unwrap();
+
+        list.sort_by(|a, b| b.0.cmp(&a.0));
+
+        assert!(list
+            .iter()
+            .map(|(_, &(key, _))| key)
+            .eq([9, 8, 8, 5, 5, 3, 3, 3, 1, 1, 0]));
+        assert!(list
+            .iter()
+            .filter(|(_, &(key, _))| key == 3)
+            .map(|(_, &(_, seq))| seq)
+            .eq([1, 3, 8]));
+
+        list.validate().unwrap();
+
+        list.remove(&links[6]).unwrap();
+        list.push_front((4, 11)).unwrap();
+
+        list.sort_by_key(|&(_, seq)| seq);
+
+        assert!(list
+            .iter()
+            .map(|(_, &(_, seq))| seq)
+            .eq([0, 1, 2, 3, 4, 5, 7, 8, 9, 10, 11]));
+
+        list.validate().unwrap();
     }
 
-    pub fn _test_list_shift_push<V>(mut list: LinkedList<V, i32>)
+    pub fn _test_list_retain_and_positional<V>(mut list: LinkedList<V, i32>)
     where
         V: Vector<Entry<Node<i32>>>,
     {
-        let capacity = list.capacity();
-
-        assert!(capacity >= 3, "Test not valid for lists with capacity < 3 ");
+        assert!(
+            list.capacity() >= 10,
+            "Test not valid for lists with capacity < 10"
+        );
 
         list.clear().unwrap();
-        assert!(list.is_empty());
 
-        for ele in 0..capacity {
-            list.push_back(ele as i32).unwrap();
+        assert_eq!(list.nth_link(0), None);
+        assert_eq!(list.find(|_| true), None);
+        assert_eq!(list.rotate_left(0), Some(()));
+        assert_eq!(list.rotate_right(1), None);
+
+        let mut links = [None; 10];
+
+        for (i, link) in links.iter_mut().enumerate() {
+            *link = Some(list.push_back(i as i32).unwrap());
         }
 
-        assert_eq!(list.peek_front(), Some(&0));
+        let links = links.map(Option::unwrap);
 
-        let link = *list.iter().find(|&(_, value)| value & 1 == 1).unwrap().0;
+        for (n, link) in links.iter().enumerate() {
+            assert_eq!(list.nth_link(n), Some(*link));
+        }
 
-        assert_eq!(list.len(), list.capacity());
+        assert_eq!(list.nth_link(10), None);
 
-        list.shift_push_front(&link).unwrap();
+        assert_eq!(list.find(|&value| value > 6), Some(links[7]));
+        assert_eq!(list.find(|&value| value > 9), None);
 
-        assert_eq!(list.len(), list.capacity());
+        assert_eq!(list.position(|&value| value % 4 == 3), Some(3));
+        assert_eq!(list.position(|&value| value < 0), None);
 
-        assert_eq!(list.peek_front(), Some(&1));
+        list.rotate_left(3).unwrap();
 
-        for (i, j) in list
+        assert!(list
             .iter()
-            .take(3)
-            .map(|(_, value)| value)
-            .zip([1, 0, 2].iter())
-        {
-            assert_eq!(i, j);
-        }
+            .map(|(_, ele)| *ele)
+            .eq([3, 4, 5, 6, 7, 8, 9, 0, 1, 2]));
+        assert_eq!(list.peek_front_link(), Some(&links[3]));
+        assert_eq!(list.peek_back_link(), Some(&links[2]));
+        list.validate().unwrap();
 
-        let link = *list.iter().find(|&(_, value)| value & 1 == 0).unwrap().0;
+        list.rotate_right(3).unwrap();
 
-        assert_eq!(list.get(&link), Some(&0));
+        assert!(list.iter().map(|(_, ele)| *ele).eq(0..10));
+        list.validate().unwrap();
 
-        assert_ne!(list.peek_back(), Some(&0));
+        list.rotate_right(8).unwrap();
 
-        assert_eq!(list.len(), list.capacity());
+        assert!(list
+            .iter()
+            .map(|(_, ele)| *ele)
+            .eq([2, 3, 4, 5, 6, 7, 8, 9, 0, 1]));
+        assert_eq!(list.nth_link(8), Some(links[0]));
+        assert_eq!(list.position(|&value| value == 0), Some(8));
 
-        list.shift_push_back(&link).unwrap();
+        assert_eq!(list.rotate_left(11), None);
+        assert_eq!(list.rotate_right(11), None);
 
-        assert_eq!(list.peek_back(), Some(&0));
+        list.rotate_left(10).unwrap();
+        list.rotate_right(0).unwrap();
+
+        assert!(list
+            .iter()
+            .map(|(_, ele)| *ele)
+            .eq([2, 3, 4, 5, 6, 7, 8, 9, 0, 1]));
+        list.validate().unwrap();
+
+        list.retain(|&value| value % 3 != 0);
+
+        assert!(list.iter().map(|(_, ele)| *ele).eq([2, 4, 5, 7, 8, 1]));
+        assert_eq!(list.len(), 6);
+
+        assert_eq!(list.get(&links[3]), None);
+        assert_eq!(list.get(&links[7]), Some(&7));
+
+        list.validate().unwrap();
+
+        list.retain(|&value| value > 4);
+
+        assert!(list.iter().map(|(_, ele)| *ele).eq([5, 7, 8]));
+        assert_eq!(list.peek_front_link(), Some(&links[5]));
+        assert_eq!(list.peek_back_link(), Some(&links[8]));
+
+        for i in 0..7 {
+            list.push_front(i).unwrap();
+        }
 
         assert_eq!(list.len(), list.capacity());
+        list.validate().unwrap();
+
+        list.retain(|_| false);
+
+        assert!(list.is_empty());
+        assert_eq!(list.peek_front_link(), None);
+        assert_eq!(list.peek_back_link(), None);
+
+        list.validate().unwrap();
     }
 }
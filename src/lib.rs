@@ -14,12 +14,12 @@ pub mod prelude {
     //! This module provides a set of commonly used items to alleviate imports.
 
     pub use super::{
-        arena::{Arena, ArenaError},
+        arena::{Arena, ArenaError, PackedIndex, ReuseStrategy, TypedIndex},
         cache::{
-            lru_cache::{LRUCache, LRUCacheError},
-            Cache, Eviction, Lookup,
+            lru_cache::{EntryHandle, LRUCache, LRUCacheError},
+            Admission, Cache, Eviction, Lookup,
         },
-        collections::list::{Link, LinkedList, ListError},
+        collections::list::{Link, LinkedList, ListArena, ListError, SharedList},
         map::{impls::alloc_btree_map::AllocBTreeMap, Map},
         vector::{
             impls::{
@@ -68,4 +68,9 @@ impl<T> Vector<T> for AllocVec<T> {
     fn clear(&mut self) {
         self.vec.clear()
     }
+
+    fn truncate(&mut self, len: usize) {
+        self.vec.truncate(len);
+        self.vec.shrink_to(len);
+    }
 }
@@ -7,6 +7,10 @@ use core::{
 };
 
 /// Implements [`Vector`] with `[T; N]`.
+///
+/// The buffer always holds `N` values. Elements removed with [`Vector::clear`] or
+/// [`Vector::truncate`] are not dropped: they stay in the buffer until they are overwritten by
+/// a later push, or the [`Array`] is dropped. Their memory is never released.
 pub struct Array<T, const N: usize> {
     buffer: [T; N],
     len: usize,
@@ -89,4 +93,8 @@ impl<T, const N: usize> Vector<T> for Array<T, N> {
     fn clear(&mut self) {
         self.len = 0;
     }
+
+    fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
 }
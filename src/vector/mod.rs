@@ -17,6 +17,11 @@ pub trait Vector<T>: DerefMut<Target = [T]> {
 
     /// Removes all elements from this vector.
     fn clear(&mut self);
+
+    /// Shortens this vector to the given length, releasing the memory held for the removed
+    /// elements where possible. Has no effect if the given length is not less than the current
+    /// length.
+    fn truncate(&mut self, len: usize);
 }
 
 pub mod impls;
@@ -83,4 +88,42 @@ pub mod tests {
             vector.push(i).unwrap();
         }
     }
+
+    pub fn _test_vector_truncate<V: Vector<usize>>(mut vector: V) {
+        vector.clear();
+
+        let capacity = vector.capacity();
+
+        assert!(
+            capacity >= 2,
+            "Test not valid for vectors with capacity < 2"
+        );
+
+        for i in 0..capacity {
+            vector.push(i).unwrap();
+        }
+
+        vector.truncate(capacity);
+        assert_eq!(vector.len(), capacity);
+
+        vector.truncate(capacity / 2);
+        assert_eq!(vector.len(), capacity / 2);
+
+        for (j, i) in vector.iter().enumerate() {
+            assert_eq!(i, &j);
+        }
+
+        assert!(vector.capacity() >= vector.len());
+
+        vector.reserve(capacity - vector.len()).unwrap();
+
+        for i in vector.len()..capacity {
+            vector.push(i).unwrap();
+        }
+
+        assert_eq!(vector.len(), capacity);
+
+        vector.truncate(0);
+        assert!(vector.is_empty());
+    }
 }
@@ -26,3 +26,170 @@ fn test_alloc_btree_array_vec_backed_lru_cache_consistency() {
         Array::<_, TEST_CAPACITY>::new(),
     );
 }
+
+#[test]
+fn test_alloc_btree_alloc_vec_backed_lru_cache_entry_handles() {
+    lru_cache::tests::_test_cache_entry_handles::<_, AllocBTreeMap<_, _>>(AllocVec::with_capacity(
+        TEST_CAPACITY,
+    ));
+}
+
+#[test]
+fn test_alloc_btree_array_vec_backed_lru_cache_entry_handles() {
+    lru_cache::tests::_test_cache_entry_handles::<_, AllocBTreeMap<_, _>>(
+        Array::<_, TEST_CAPACITY>::new(),
+    );
+}
+
+#[test]
+fn test_alloc_btree_alloc_vec_backed_lru_cache_pinning() {
+    lru_cache::tests::_test_cache_pinning::<_, AllocBTreeMap<_, _>>(AllocVec::with_capacity(
+        TEST_CAPACITY,
+    ));
+}
+
+#[test]
+fn test_alloc_btree_array_vec_backed_lru_cache_pinning() {
+    lru_cache::tests::_test_cache_pinning::<_, AllocBTreeMap<_, _>>(
+        Array::<_, TEST_CAPACITY>::new(),
+    );
+}
+
+#[test]
+fn test_alloc_btree_alloc_vec_backed_lru_cache_batch_operations() {
+    lru_cache::tests::_test_cache_batch_operations::<_, AllocBTreeMap<_, _>>(
+        AllocVec::with_capacity(TEST_CAPACITY),
+    );
+}
+
+#[test]
+fn test_alloc_btree_array_vec_backed_lru_cache_batch_operations() {
+    lru_cache::tests::_test_cache_batch_operations::<_, AllocBTreeMap<_, _>>(Array::<
+        _,
+        TEST_CAPACITY,
+    >::new());
+}
+
+#[test]
+fn test_alloc_btree_alloc_vec_backed_lru_cache_retain_drain() {
+    lru_cache::tests::_test_cache_retain_drain::<_, AllocBTreeMap<_, _>>(AllocVec::with_capacity(
+        TEST_CAPACITY,
+    ));
+}
+
+#[test]
+fn test_alloc_btree_array_vec_backed_lru_cache_retain_drain() {
+    lru_cache::tests::_test_cache_retain_drain::<_, AllocBTreeMap<_, _>>(
+        Array::<_, TEST_CAPACITY>::new(),
+    );
+}
+
+#[test]
+fn test_alloc_btree_alloc_vec_backed_lru_cache_conditional_inserts() {
+    lru_cache::tests::_test_cache_conditional_inserts::<_, AllocBTreeMap<_, _>>(
+        AllocVec::with_capacity(TEST_CAPACITY),
+    );
+}
+
+#[test]
+fn test_alloc_btree_array_vec_backed_lru_cache_conditional_inserts() {
+    lru_cache::tests::_test_cache_conditional_inserts::<_, AllocBTreeMap<_, _>>(Array::<
+        _,
+        TEST_CAPACITY,
+    >::new());
+}
+
+#[test]
+fn test_alloc_btree_alloc_vec_backed_lru_cache_promote_demote_pop() {
+    lru_cache::tests::_test_cache_promote_demote_pop::<_, AllocBTreeMap<_, _>>(
+        AllocVec::with_capacity(TEST_CAPACITY),
+    );
+}
+
+#[test]
+fn test_alloc_btree_array_vec_backed_lru_cache_promote_demote_pop() {
+    lru_cache::tests::_test_cache_promote_demote_pop::<_, AllocBTreeMap<_, _>>(Array::<
+        _,
+        TEST_CAPACITY,
+    >::new());
+}
+
+#[test]
+fn test_alloc_vec_backed_lru_cache_transactional_operations() {
+    lru_cache::tests::_test_cache_transactional_operations(AllocVec::with_capacity(TEST_CAPACITY));
+}
+
+#[test]
+fn test_array_vec_backed_lru_cache_transactional_operations() {
+    lru_cache::tests::_test_cache_transactional_operations(Array::<_, TEST_CAPACITY>::new());
+}
+
+#[test]
+fn test_alloc_btree_alloc_vec_backed_lru_cache_resize() {
+    lru_cache::tests::_test_cache_resize::<_, AllocBTreeMap<_, _>>(AllocVec::with_capacity(
+        TEST_CAPACITY,
+    ));
+}
+
+#[test]
+fn test_alloc_btree_array_vec_backed_lru_cache_resize() {
+    lru_cache::tests::_test_cache_resize::<_, AllocBTreeMap<_, _>>(Array::<_, TEST_CAPACITY>::new());
+}
+
+#[test]
+fn test_alloc_btree_alloc_vec_backed_lru_cache_fallible_construction() {
+    lru_cache::tests::_test_cache_fallible_construction::<_, _, AllocBTreeMap<_, _>>(
+        AllocVec::with_capacity(0),
+        AllocVec::with_capacity(TEST_CAPACITY),
+    );
+}
+
+#[test]
+fn test_alloc_btree_array_vec_backed_lru_cache_fallible_construction() {
+    lru_cache::tests::_test_cache_fallible_construction::<_, _, AllocBTreeMap<_, _>>(
+        Array::<_, 0>::new(),
+        Array::<_, TEST_CAPACITY>::new(),
+    );
+}
+
+#[test]
+fn test_alloc_btree_alloc_vec_backed_lru_cache_packed_index() {
+    lru_cache::tests::_test_cache_packed_index::<_, AllocBTreeMap<_, _>>(AllocVec::with_capacity(
+        TEST_CAPACITY,
+    ));
+}
+
+#[test]
+fn test_alloc_btree_array_vec_backed_lru_cache_packed_index() {
+    lru_cache::tests::_test_cache_packed_index::<_, AllocBTreeMap<_, _>>(
+        Array::<_, TEST_CAPACITY>::new(),
+    );
+}
+
+#[test]
+fn test_alloc_btree_alloc_vec_backed_lru_cache_compact() {
+    lru_cache::tests::_test_cache_compact::<_, AllocBTreeMap<_, _>>(AllocVec::with_capacity(
+        TEST_CAPACITY,
+    ));
+}
+
+#[test]
+fn test_alloc_btree_array_vec_backed_lru_cache_compact() {
+    lru_cache::tests::_test_cache_compact::<_, AllocBTreeMap<_, _>>(
+        Array::<_, TEST_CAPACITY>::new(),
+    );
+}
+
+#[test]
+fn test_alloc_btree_alloc_vec_backed_lru_cache_validate() {
+    lru_cache::tests::_test_cache_validate::<_, AllocBTreeMap<_, _>>(AllocVec::with_capacity(
+        TEST_CAPACITY,
+    ));
+}
+
+#[test]
+fn test_alloc_btree_array_vec_backed_lru_cache_validate() {
+    lru_cache::tests::_test_cache_validate::<_, AllocBTreeMap<_, _>>(
+        Array::<_, TEST_CAPACITY>::new(),
+    );
+}
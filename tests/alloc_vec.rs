@@ -1,6 +1,6 @@
 use generational_cache::{
-    arena::{self, Arena, Entry},
-    collections::list::{self, LinkedList, LinkedListArenaEntry},
+    arena::{self, Arena, ArenaEntry, Entry, Index, IndexFor, PackedIndex, TypedIndex},
+    collections::list::{self, LinkedList, LinkedListArenaEntry, ListArena},
     vector::{self, impls::alloc_vec::AllocVec},
 };
 
@@ -10,6 +10,13 @@ pub fn alloc_vec_backed_arena<T>(capacity: usize) -> Arena<AllocVec<Entry<T>>, T
     Arena::with_vector(AllocVec::with_capacity(capacity))
 }
 
+pub fn alloc_vec_backed_indexed_arena<I>() -> Arena<AllocVec<ArenaEntry<i32, I>>, i32, I>
+where
+    I: IndexFor<i32>,
+{
+    Arena::with_vector(AllocVec::with_capacity(TEST_CAPACITY))
+}
+
 pub fn alloc_vec_backed_list<T>(
     capacity: usize,
 ) -> LinkedList<AllocVec<LinkedListArenaEntry<T>>, T> {
@@ -69,3 +76,146 @@ fn test_alloc_vec_list_remove() {
 fn test_alloc_vec_list_shift_push() {
     list::tests::_test_list_shift_push(alloc_vec_backed_list::<i32>(TEST_CAPACITY));
 }
+
+#[test]
+fn test_alloc_vec_list_link_navigation() {
+    list::tests::_test_list_link_navigation(alloc_vec_backed_list::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_vector_truncate() {
+    vector::tests::_test_vector_truncate(AllocVec::<usize>::with_capacity(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_arena_shrink_to() {
+    arena::tests::_test_arena_shrink_to(alloc_vec_backed_arena::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_arena_generations() {
+    arena::tests::_test_arena_generations(alloc_vec_backed_arena::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_list_shrink_to() {
+    list::tests::_test_list_shrink_to(alloc_vec_backed_list::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_arena_index_types() {
+    arena::tests::_test_arena_index_types(alloc_vec_backed_indexed_arena::<Index<u16>>());
+    arena::tests::_test_arena_index_types(alloc_vec_backed_indexed_arena::<Index<u32>>());
+    arena::tests::_test_arena_index_types(alloc_vec_backed_indexed_arena::<PackedIndex>());
+    arena::tests::_test_arena_index_types(alloc_vec_backed_indexed_arena::<TypedIndex<i32>>());
+    arena::tests::_test_arena_index_types(alloc_vec_backed_indexed_arena::<
+        TypedIndex<i32, PackedIndex>,
+    >());
+}
+
+#[test]
+fn test_packed_index() {
+    arena::tests::_test_packed_index();
+}
+
+#[test]
+fn test_alloc_vec_arena_iteration() {
+    arena::tests::_test_arena_iteration(alloc_vec_backed_arena::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_arena_insert_with() {
+    arena::tests::_test_arena_insert_with(alloc_vec_backed_arena::<Index>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_arena_get_many_mut() {
+    arena::tests::_test_arena_get_many_mut(alloc_vec_backed_arena::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_arena_reuse_strategies() {
+    arena::tests::_test_arena_reuse_strategies(alloc_vec_backed_arena::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_arena_validate() {
+    arena::tests::_test_arena_validate(alloc_vec_backed_arena::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_arena_compact() {
+    arena::tests::_test_arena_compact(alloc_vec_backed_arena::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_list_validate() {
+    list::tests::_test_list_validate(alloc_vec_backed_list::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_list_cursor() {
+    list::tests::_test_list_cursor(alloc_vec_backed_list::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_list_cursor_insert_list() {
+    list::tests::_test_list_cursor_insert_list(
+        alloc_vec_backed_list::<i32>(TEST_CAPACITY),
+        alloc_vec_backed_list::<i32>(TEST_CAPACITY),
+    );
+}
+
+#[test]
+fn test_alloc_vec_list_insert_and_move() {
+    list::tests::_test_list_insert_and_move(alloc_vec_backed_list::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_list_iterators() {
+    list::tests::_test_list_iterators(alloc_vec_backed_list::<i32>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_shared_lists() {
+    list::tests::_test_shared_lists(ListArena::with_backing_vector(AllocVec::with_capacity(
+        TEST_CAPACITY,
+    )));
+}
+
+#[test]
+fn test_alloc_vec_shared_lists_shrink_to() {
+    list::tests::_test_shared_lists_shrink_to(ListArena::with_backing_vector(
+        AllocVec::with_capacity(TEST_CAPACITY),
+    ));
+}
+
+#[test]
+fn test_alloc_vec_shared_list_foreign_links() {
+    list::tests::_test_shared_list_foreign_links(ListArena::with_backing_vector(
+        AllocVec::with_capacity(TEST_CAPACITY),
+    ));
+}
+
+#[test]
+fn test_alloc_vec_list_append_split_off_values() {
+    list::tests::_test_list_append_split_off_values(
+        alloc_vec_backed_list::<i32>(TEST_CAPACITY),
+        alloc_vec_backed_list::<i32>(TEST_CAPACITY),
+    );
+}
+
+#[test]
+fn test_alloc_vec_list_sort() {
+    list::tests::_test_list_sort(alloc_vec_backed_list::<(i32, usize)>(TEST_CAPACITY));
+}
+
+#[test]
+fn test_alloc_vec_list_retain_and_positional() {
+    list::tests::_test_list_retain_and_positional(alloc_vec_backed_list::<i32>(10));
+}
+
+#[test]
+fn test_alloc_vec_list_compact() {
+    list::tests::_test_list_compact(alloc_vec_backed_list::<i32>(TEST_CAPACITY));
+}
@@ -1,7 +1,7 @@
 use core::marker::Copy;
 use generational_cache::{
-    arena::{self, Arena, Entry},
-    collections::list::{self, LinkedList, LinkedListArenaEntry},
+    arena::{self, Arena, ArenaEntry, Entry, Index, IndexFor, PackedIndex, TypedIndex},
+    collections::list::{self, LinkedList, LinkedListArenaEntry, ListArena},
     vector::{self, impls::array::Array},
 };
 
@@ -14,6 +14,13 @@ where
     Arena::with_vector(Array::new())
 }
 
+pub fn array_backed_indexed_arena<I>() -> Arena<Array<ArenaEntry<i32, I>, TEST_CAPACITY>, i32, I>
+where
+    I: IndexFor<i32>,
+{
+    Arena::with_vector(Array::new())
+}
+
 pub fn array_backed_list<T, const N: usize>() -> LinkedList<Array<LinkedListArenaEntry<T>, N>, T>
 where
     T: Copy + Default,
@@ -31,6 +38,11 @@ fn test_array_arena_free_entries_init() {
     arena::tests::_test_arena_free_entries_init(array_backed_arena::<(), TEST_CAPACITY>());
 }
 
+#[test]
+fn test_array_arena_reserve() {
+    arena::tests::_test_arena_reserve(array_backed_arena::<(), TEST_CAPACITY>());
+}
+
 #[test]
 fn test_array_arena_insert() {
     arena::tests::_test_arena_insert(array_backed_arena::<i32, TEST_CAPACITY>());
@@ -69,3 +81,158 @@ fn test_array_list_remove() {
 fn test_array_list_shift_push() {
     list::tests::_test_list_shift_push(array_backed_list::<i32, TEST_CAPACITY>());
 }
+
+#[test]
+fn test_array_list_link_navigation() {
+    list::tests::_test_list_link_navigation(array_backed_list::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_vector_truncate() {
+    vector::tests::_test_vector_truncate(Array::<usize, TEST_CAPACITY>::new());
+}
+
+#[test]
+fn test_array_arena_shrink_to() {
+    arena::tests::_test_arena_shrink_to(array_backed_arena::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_arena_reserve_rollback() {
+    arena::tests::_test_arena_reserve_rollback(Array::<_, TEST_CAPACITY>::new());
+}
+
+#[test]
+fn test_array_arena_generations() {
+    arena::tests::_test_arena_generations(array_backed_arena::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_list_shrink_to() {
+    list::tests::_test_list_shrink_to(array_backed_list::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_arena_try_with_vector() {
+    arena::tests::_test_arena_try_with_vector::<i32, _>(
+        Array::<_, TEST_CAPACITY>::new(),
+        Array::<_, TEST_CAPACITY>::new(),
+    );
+}
+
+#[test]
+fn test_array_list_try_with_backing_vector() {
+    list::tests::_test_list_try_with_backing_vector::<i32, _>(
+        Array::<_, TEST_CAPACITY>::new(),
+        Array::<_, TEST_CAPACITY>::new(),
+    );
+}
+
+#[test]
+fn test_array_arena_index_types() {
+    arena::tests::_test_arena_index_types(array_backed_indexed_arena::<Index<u16>>());
+    arena::tests::_test_arena_index_types(array_backed_indexed_arena::<Index<u32>>());
+    arena::tests::_test_arena_index_types(array_backed_indexed_arena::<PackedIndex>());
+    arena::tests::_test_arena_index_types(array_backed_indexed_arena::<TypedIndex<i32>>());
+    arena::tests::_test_arena_index_types(
+        array_backed_indexed_arena::<TypedIndex<i32, PackedIndex>>(),
+    );
+}
+
+#[test]
+fn test_array_arena_iteration() {
+    arena::tests::_test_arena_iteration(array_backed_arena::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_arena_insert_with() {
+    arena::tests::_test_arena_insert_with(Arena::with_vector(Array::<_, TEST_CAPACITY>::new()));
+}
+
+#[test]
+fn test_array_arena_get_many_mut() {
+    arena::tests::_test_arena_get_many_mut(array_backed_arena::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_arena_reuse_strategies() {
+    arena::tests::_test_arena_reuse_strategies(array_backed_arena::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_arena_validate() {
+    arena::tests::_test_arena_validate(array_backed_arena::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_arena_compact() {
+    arena::tests::_test_arena_compact(array_backed_arena::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_list_validate() {
+    list::tests::_test_list_validate(array_backed_list::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_list_cursor() {
+    list::tests::_test_list_cursor(array_backed_list::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_list_cursor_insert_list() {
+    list::tests::_test_list_cursor_insert_list(
+        array_backed_list::<i32, TEST_CAPACITY>(),
+        array_backed_list::<i32, TEST_CAPACITY>(),
+    );
+}
+
+#[test]
+fn test_array_list_insert_and_move() {
+    list::tests::_test_list_insert_and_move(array_backed_list::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_list_iterators() {
+    list::tests::_test_list_iterators(array_backed_list::<i32, TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_shared_lists() {
+    list::tests::_test_shared_lists(ListArena::<Array<_, TEST_CAPACITY>, i32>::default());
+}
+
+#[test]
+fn test_array_shared_lists_shrink_to() {
+    list::tests::_test_shared_lists_shrink_to(ListArena::<Array<_, TEST_CAPACITY>, i32>::default());
+}
+
+#[test]
+fn test_array_shared_list_foreign_links() {
+    list::tests::_test_shared_list_foreign_links(
+        ListArena::<Array<_, TEST_CAPACITY>, i32>::default(),
+    );
+}
+
+#[test]
+fn test_array_list_append_split_off_values() {
+    list::tests::_test_list_append_split_off_values(
+        array_backed_list::<i32, TEST_CAPACITY>(),
+        array_backed_list::<i32, TEST_CAPACITY>(),
+    );
+}
+
+#[test]
+fn test_array_list_sort() {
+    list::tests::_test_list_sort(array_backed_list::<(i32, usize), TEST_CAPACITY>());
+}
+
+#[test]
+fn test_array_list_retain_and_positional() {
+    list::tests::_test_list_retain_and_positional(array_backed_list::<i32, 10>());
+}
+
+#[test]
+fn test_array_list_compact() {
+    list::tests::_test_list_compact(array_backed_list::<i32, TEST_CAPACITY>());
+}